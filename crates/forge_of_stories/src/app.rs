@@ -1,13 +1,21 @@
+use crate::console;
+use crate::ui::notifications::{self, Notify, NotifyIcon};
+use crate::ui::scenes::in_game::cameras::CameraSettings;
+use crate::ui::scenes::in_game::photo_mode::ScreenshotSettings;
 use bevy::{log::LogPlugin, prelude::*};
 use bevy_paths::{PathMarker, PathRegistry, PathRegistryPlugin};
 use bevy_settings::{SerializationFormat, SettingsPlugin};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::{filter::filter_fn, fmt, prelude::*};
 
 const APP_ID: &'static str = "forge_of_stories";
 const STUDIO: &'static str = "chicken105";
 const PROJECT_ID: &'static str = "forge_of_stories";
+/// Quiet time after the last settings change before "Settings saved" is shown
+const SETTINGS_SAVED_DELAY_SECS: f32 = 0.5;
 
 #[derive(PathMarker, Resource)]
 pub struct LogsDir;
@@ -15,6 +23,21 @@ pub struct LogsDir;
 #[derive(PathMarker, Resource)]
 pub struct SettingsDir;
 
+#[derive(PathMarker, Resource)]
+pub struct DataDir;
+
+#[derive(PathMarker, Resource)]
+pub struct ScreenshotsDir;
+
+/// Name of the world being played, inserted when a game session starts
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct WorldName(pub String);
+
+impl WorldName {
+    /// World the embedded singleplayer server runs
+    pub const SINGLEPLAYER: &'static str = "Default World";
+}
+
 pub fn init() -> App {
     // Create PathContext with studio/project/app hierarchy
     let mut paths_plugin = PathRegistryPlugin::new(STUDIO, PROJECT_ID, APP_ID);
@@ -33,6 +56,12 @@ pub fn init() -> App {
     paths_plugin = paths_plugin
         .register::<SettingsDir>("settings/")
         .expect("Failed to register SettingsDir");
+    paths_plugin = paths_plugin
+        .register::<DataDir>("data/")
+        .expect("Failed to register DataDir");
+    paths_plugin = paths_plugin
+        .register::<ScreenshotsDir>("screenshots/")
+        .expect("Failed to register ScreenshotsDir");
 
     let mut app = App::new();
     app.add_plugins(
@@ -51,12 +80,42 @@ pub fn init() -> App {
 
     let registry = app.world().resource::<PathRegistry>();
     let settings_file = registry.get::<SettingsDir>().unwrap().join("settings.json");
+    app.add_plugins(
+        SettingsPlugin::new(settings_file, SerializationFormat::Json)
+            .register::<CameraSettings>("camera")
+            .register::<ScreenshotSettings>("screenshots"),
+    );
 
-    app.add_systems(Startup, setup_logging);
+    app.add_systems(Startup, setup_logging)
+        .add_systems(Update, notify_settings_saved);
     println!("log");
     app
 }
 
+/// Confirms changed settings with a toast once they settled; the settings plugin writes them
+/// to the settings file on change.
+fn notify_settings_saved(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    camera: Res<CameraSettings>,
+    screenshots: Res<ScreenshotSettings>,
+    mut pending: Local<Option<f32>>,
+) {
+    let now = time.elapsed_secs();
+    let changed = |is_changed: bool, is_added: bool| is_changed && !is_added;
+    if changed(camera.is_changed(), camera.is_added())
+        || changed(screenshots.is_changed(), screenshots.is_added())
+    {
+        // Sliders and repeated console commands change settings every frame
+        *pending = Some(now + SETTINGS_SAVED_DELAY_SECS);
+    }
+
+    if pending.is_some_and(|at| now >= at) {
+        *pending = None;
+        commands.trigger(Notify::success("Settings saved").with_icon(NotifyIcon::Settings));
+    }
+}
+
 fn setup_logging(world: &mut World) {
     // Logging
     let registry = world.resource::<PathRegistry>();
@@ -83,23 +142,96 @@ fn setup_logging(world: &mut World) {
     #[cfg(not(debug_assertions))]
     let level = LevelFilter::WARN;
 
+    // Shared with the developer console (`log level <target> <level>`)
+    let levels = LogLevels::new(level);
+    world.insert_resource(levels.clone());
+
     // Separate layer: file (non-blocking) + console (stdout)
+    let file_levels = levels.clone();
     let file_layer = fmt::Layer::default()
         .with_target(true)
         .with_ansi(false)
         .with_writer(non_blocking)
-        .with_filter(filter_fn(move |metadata| metadata.level() <= &level));
+        .with_filter(filter_fn(move |metadata| {
+            file_levels.enabled(metadata.target(), metadata.level())
+        }));
 
+    let console_levels = levels.clone();
     let console_layer = fmt::Layer::default()
         .with_target(true)
-        .with_filter(filter_fn(move |metadata| metadata.level() <= &level));
+        .with_filter(filter_fn(move |metadata| {
+            console_levels.enabled(metadata.target(), metadata.level())
+        }));
+
+    // Routed log events are shown as toasts (see `LogToastRoutes`)
+    let toast_layer = notifications::toast_layer(world);
+
+    // Log output mirrored into the developer console
+    let dev_console_layer =
+        console::dev_console_layer(world).with_filter(filter_fn(move |metadata| {
+            levels.enabled(metadata.target(), metadata.level())
+        }));
 
     tracing_subscriber::registry()
         .with(file_layer)
         .with(console_layer)
+        .with(toast_layer)
+        .with(dev_console_layer)
         .init();
 }
 
+/// Log level filter with per-target overrides that can be changed at runtime.
+///
+/// Targets match by prefix, the longest matching override wins (e.g. an override for `client`
+/// also applies to `client/host` unless that has its own).
+#[derive(Resource, Clone)]
+pub struct LogLevels {
+    default: LevelFilter,
+    overrides: Arc<RwLock<HashMap<String, LevelFilter>>>,
+}
+
+impl LogLevels {
+    pub fn new(default: LevelFilter) -> Self {
+        Self {
+            default,
+            overrides: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Sets the level for `target` and everything below it.
+    pub fn set(&self, target: impl Into<String>, level: LevelFilter) {
+        if let Ok(mut overrides) = self.overrides.write() {
+            overrides.insert(target.into(), level);
+        }
+        // Filters cache their decision per callsite
+        tracing::callsite::rebuild_interest_cache();
+    }
+
+    /// Removes the override for `target`, falling back to the default level.
+    pub fn reset(&self, target: &str) {
+        if let Ok(mut overrides) = self.overrides.write() {
+            overrides.remove(target);
+        }
+        tracing::callsite::rebuild_interest_cache();
+    }
+
+    pub fn enabled(&self, target: &str, level: &tracing::Level) -> bool {
+        let filter = self
+            .overrides
+            .read()
+            .ok()
+            .and_then(|overrides| {
+                overrides
+                    .iter()
+                    .filter(|(prefix, _)| target.starts_with(prefix.as_str()))
+                    .max_by_key(|(prefix, _)| prefix.len())
+                    .map(|(_, filter)| *filter)
+            })
+            .unwrap_or(self.default);
+        level <= &filter
+    }
+}
+
 pub const LOG_MAIN: &str = "main";
 pub const LOG_CLIENT_HOST: &str = "client/host";
 pub const LOG_CLIENT_APP: &str = "client/app";
@@ -0,0 +1,271 @@
+//! In-game text chat.
+//!
+//! Chat messages are sent to the server as a reliable client event and broadcast back to
+//! every client as a server event. The server is authoritative for length limits and rate
+//! limiting; the client applies the same rules before sending so the player gets immediate
+//! feedback instead of a silently dropped message, and sanitizes received lines again so a
+//! misbehaving peer can't flood the history with oversized text.
+//!
+//! The received history is kept in [`ChatLog`], which the chat HUD renders.
+
+use crate::GameState;
+use crate::app::LOG_CLIENT;
+use crate::networking::LocalPlayer;
+use crate::ui::notifications::{Notify, NotifyIcon};
+use crate::ui::scenes::in_game::world::DisplayName;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use game_server::Player;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Maximum number of characters in a single chat message.
+pub const MAX_CHAT_MESSAGE_LEN: usize = 200;
+/// Maximum number of messages a client may send within [`CHAT_RATE_WINDOW_SECS`].
+pub const CHAT_RATE_LIMIT: usize = 5;
+/// Sliding window for chat rate limiting, in seconds.
+pub const CHAT_RATE_WINDOW_SECS: f32 = 5.0;
+/// Number of entries kept in the local chat history.
+const CHAT_HISTORY_LEN: usize = 50;
+
+/// Plugin for the chat subsystem (local history; the network events are registered by
+/// [`ProtocolPlugin`](crate::protocol::ProtocolPlugin)).
+pub struct ChatPlugin;
+
+impl Plugin for ChatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatLog>()
+            .add_observer(receive_chat_broadcast)
+            .add_systems(
+                Update,
+                announce_player_changes.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnExit(GameState::InGame), clear_chat_log);
+    }
+}
+
+/// Client → Server: a chat message typed by the player.
+#[derive(Event, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatMessage {
+    pub text: String,
+}
+
+/// Server → Clients: a chat line to show in every client's history.
+#[derive(Event, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatBroadcast {
+    pub sender: ChatSender,
+    pub text: String,
+}
+
+/// Origin of a chat line.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ChatSender {
+    /// Server or client generated notices (join/leave, rate limit warnings).
+    System,
+    /// A message written by a player.
+    Player { name: String, color: Color },
+}
+
+/// Why a chat message was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatRejection {
+    /// The message was empty after trimming.
+    Empty,
+    /// Too many messages were sent within the rate window.
+    RateLimited,
+}
+
+/// Normalizes a chat message: replaces runs of tabs and line breaks with a single space,
+/// strips other control characters, trims whitespace and truncates it to
+/// [`MAX_CHAT_MESSAGE_LEN`] characters.
+pub fn sanitize_chat_message(text: &str) -> Result<String, ChatRejection> {
+    let mut spaced = String::with_capacity(text.len());
+    let mut in_break = false;
+    for c in text.chars() {
+        if c.is_control() {
+            // Tabs and line breaks still separate words
+            if c.is_whitespace() && !in_break {
+                spaced.push(' ');
+                in_break = true;
+            }
+            continue;
+        }
+        in_break = false;
+        spaced.push(c);
+    }
+
+    let cleaned: String = spaced.trim().chars().take(MAX_CHAT_MESSAGE_LEN).collect();
+
+    if cleaned.is_empty() {
+        Err(ChatRejection::Empty)
+    } else {
+        Ok(cleaned)
+    }
+}
+
+/// Sliding-window rate limiter for chat messages (one per client).
+#[derive(Debug, Default, Clone)]
+pub struct ChatRateLimiter {
+    sent_at: VecDeque<f32>,
+}
+
+impl ChatRateLimiter {
+    /// Records a message sent at `now` (seconds) if the client is within its budget.
+    pub fn try_send(&mut self, now: f32) -> Result<(), ChatRejection> {
+        while self
+            .sent_at
+            .front()
+            .is_some_and(|sent| now - sent > CHAT_RATE_WINDOW_SECS)
+        {
+            self.sent_at.pop_front();
+        }
+
+        if self.sent_at.len() >= CHAT_RATE_LIMIT {
+            return Err(ChatRejection::RateLimited);
+        }
+
+        self.sent_at.push_back(now);
+        Ok(())
+    }
+}
+
+/// A single line in the chat history.
+#[derive(Debug, Clone)]
+pub struct ChatEntry {
+    pub sender: ChatSender,
+    pub text: String,
+    /// Elapsed time (seconds) when the line was received, used for fading.
+    pub received_at: f32,
+}
+
+/// Resource holding the received chat history, newest last.
+#[derive(Resource, Default, Debug)]
+pub struct ChatLog {
+    entries: VecDeque<ChatEntry>,
+}
+
+impl ChatLog {
+    pub fn push(&mut self, sender: ChatSender, text: impl Into<String>, received_at: f32) {
+        if self.entries.len() >= CHAT_HISTORY_LEN {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(ChatEntry {
+            sender,
+            text: text.into(),
+            received_at,
+        });
+    }
+
+    pub fn push_system(&mut self, text: impl Into<String>, received_at: f32) {
+        self.push(ChatSender::System, text, received_at);
+    }
+
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &ChatEntry> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+fn receive_chat_broadcast(broadcast: On<ChatBroadcast>, time: Res<Time>, mut log: ResMut<ChatLog>) {
+    // Apply the same limits as the server, received lines are not trusted blindly
+    let Ok(text) = sanitize_chat_message(&broadcast.text) else {
+        return;
+    };
+    if let ChatSender::Player { name, .. } = &broadcast.sender {
+        info!(target: LOG_CLIENT, "[chat] {}: {}", name, text);
+    }
+    log.push(broadcast.sender.clone(), text, time.elapsed_secs());
+}
+
+/// Adds a system message naming every replicated player that joins (except ourselves) or
+/// leaves.
+///
+/// Names are remembered per entity, a despawned player no longer has its `DisplayName`.
+fn announce_player_changes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut log: ResMut<ChatLog>,
+    mut known: Local<EntityHashMap<String>>,
+    players: Query<(Entity, Option<Ref<DisplayName>>, Has<LocalPlayer>), With<Player>>,
+    joined: Query<(), Added<Player>>,
+    mut removed: RemovedComponents<Player>,
+) {
+    for (entity, name, is_local) in &players {
+        if name.as_ref().is_some_and(|name| name.is_changed()) || !known.contains_key(&entity) {
+            known.insert(entity, DisplayName::of(name.as_deref()).to_string());
+        }
+        if is_local || !joined.contains(entity) {
+            continue;
+        }
+        let message = format!("{} joined the game", known[&entity]);
+        commands.trigger(Notify::info(message.clone()).with_icon(NotifyIcon::User));
+        log.push_system(message, time.elapsed_secs());
+    }
+
+    for entity in removed.read() {
+        let Some(name) = known.remove(&entity) else {
+            continue;
+        };
+        let message = format!("{name} left the game");
+        commands.trigger(Notify::info(message.clone()).with_icon(NotifyIcon::User));
+        log.push_system(message, time.elapsed_secs());
+    }
+}
+
+fn clear_chat_log(mut log: ResMut<ChatLog>) {
+    log.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_trims_and_strips_control_characters() {
+        assert_eq!(
+            sanitize_chat_message("  hello\tworld\u{7}\n "),
+            Ok("hello world".to_string())
+        );
+        assert_eq!(
+            sanitize_chat_message("ünïcödé ✓"),
+            Ok("ünïcödé ✓".to_string())
+        );
+    }
+
+    #[test]
+    fn sanitize_turns_whitespace_control_characters_into_a_space() {
+        assert_eq!(sanitize_chat_message("a\tb"), Ok("a b".to_string()));
+        assert_eq!(sanitize_chat_message("a\r\nb"), Ok("a b".to_string()));
+        assert_eq!(sanitize_chat_message("a\t\u{7}\nb"), Ok("a b".to_string()));
+        assert_eq!(sanitize_chat_message("a\u{7}b"), Ok("ab".to_string()));
+    }
+
+    #[test]
+    fn sanitize_rejects_empty_messages() {
+        assert_eq!(sanitize_chat_message(""), Err(ChatRejection::Empty));
+        assert_eq!(sanitize_chat_message(" \n\t\r "), Err(ChatRejection::Empty));
+    }
+
+    #[test]
+    fn sanitize_truncates_by_characters() {
+        let long = "ä".repeat(MAX_CHAT_MESSAGE_LEN + 10);
+        let sanitized = sanitize_chat_message(&long).unwrap();
+        assert_eq!(sanitized.chars().count(), MAX_CHAT_MESSAGE_LEN);
+    }
+
+    #[test]
+    fn rate_limiter_allows_a_burst_then_refills() {
+        let mut limiter = ChatRateLimiter::default();
+        for _ in 0..CHAT_RATE_LIMIT {
+            assert_eq!(limiter.try_send(0.0), Ok(()));
+        }
+        assert_eq!(limiter.try_send(1.0), Err(ChatRejection::RateLimited));
+        // Rejected messages don't use up the budget of the next window
+        for _ in 0..CHAT_RATE_LIMIT {
+            assert_eq!(limiter.try_send(CHAT_RATE_WINDOW_SECS + 0.1), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,873 @@
+//! Developer console.
+//!
+//! A drop-down console (toggled with **`**) that runs commands from a [`ConsoleRegistry`].
+//! Plugins add their own commands with [`ConsoleAppExt::register_console_command`]:
+//!
+//! ```ignore
+//! app.register_console_command(
+//!     ConsoleCommand::new("god", toggle_god_mode).usage("god").help("Toggle invulnerability"),
+//! );
+//! ```
+//!
+//! The command history is saved under the data directory, and log output is mirrored into
+//! the console through a tracing layer (see [`dev_console_layer`]).
+
+use crate::GameState;
+use crate::app::{DataDir, LOG_CLIENT, LogLevels};
+use crate::networking::LocalPlayer;
+use crate::ui::scenes::camera_path::PathPlayback;
+use crate::ui::scenes::in_game::cameras::{
+    CameraMode, CameraTrauma, InGameCamera, LocalPlayerSlot, LocalPlayers, PlayCutscene,
+    SetCameraMode, StopCutscene,
+};
+use crate::ui::scenes::in_game::player_list::PlayerRole;
+use bevy::prelude::*;
+use bevy_paths::PathRegistry;
+use bevy_replicon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use tracing::field::{Field, Visit};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+/// Number of output lines kept in the console.
+const MAX_OUTPUT_LINES: usize = 500;
+/// Number of history entries kept and saved.
+const MAX_HISTORY: usize = 100;
+const HISTORY_FILE: &str = "console_history.txt";
+
+/// Plugin for the developer console command registry and built-in commands
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleRegistry>()
+            .init_resource::<ConsoleOutput>()
+            .init_resource::<ConsoleHistory>()
+            .add_systems(Startup, load_console_history)
+            .add_systems(Update, forward_log_lines);
+
+        app.register_console_command(
+            ConsoleCommand::new("help", help_command)
+                .usage("help [command]")
+                .help("List commands or show the usage of one"),
+        )
+        .register_console_command(
+            ConsoleCommand::new("clear", clear_command).help("Clear the console output"),
+        )
+        .register_console_command(
+            ConsoleCommand::new("state", state_command)
+                .usage("state <GameState>")
+                .help("Switch the game state (only transitions valid from the current one)")
+                .completions(0, GAME_STATES.iter().map(|state| format!("{state:?}"))),
+        )
+        .register_console_command(
+            ConsoleCommand::new("camera", camera_command)
+                .usage("camera <mode> [player]")
+                .help("Switch the in-game camera mode of a player (default: player 1)")
+                .completions(0, CameraMode::ALL.iter().map(|mode| format!("{mode:?}"))),
+        )
+        .register_console_command(
+            ConsoleCommand::new("shake", shake_command)
+                .usage("shake <trauma> [player]")
+                .help("Add camera shake trauma (0..1), to every player unless one is given"),
+        )
+        .register_console_command(
+            ConsoleCommand::new("cutscene", cutscene_command)
+                .usage("cutscene <path.campath.json|stop> [once|loop|pingpong]")
+                .help("Play a camera path asset on the in-game camera")
+                .completions(0, ["stop"])
+                .completions(1, ["once", "loop", "pingpong"]),
+        )
+        .register_console_command(
+            ConsoleCommand::new("tp", tp_command)
+                .usage("tp <x> <y> <z>")
+                .help("Teleport the local player (admin, sent to the server)"),
+        )
+        .register_console_command(
+            ConsoleCommand::new("log", log_command)
+                .usage("log level <target> <trace|debug|info|warn|error|off|reset>")
+                .help("Change the log level of a target at runtime")
+                .completions(0, ["level"])
+                .completions(
+                    1,
+                    [
+                        crate::app::LOG_MAIN,
+                        crate::app::LOG_CLIENT_HOST,
+                        LOG_CLIENT,
+                    ],
+                )
+                .completions(
+                    2,
+                    ["trace", "debug", "info", "warn", "error", "off", "reset"],
+                ),
+        )
+        .register_console_command(ConsoleCommand::new("quit", quit_command).help("Quit the game"));
+    }
+}
+
+/// Admin request to teleport the sending player. Validated and applied by the server.
+#[derive(Event, Serialize, Deserialize, Clone, Debug)]
+pub struct AdminTeleport {
+    pub position: Vec3,
+}
+
+/// Function executed for a console command. Errors are printed to the console.
+pub type ConsoleHandler = fn(&mut World, &ConsoleArgs) -> Result<(), String>;
+
+/// A command that can be run from the developer console
+#[derive(Clone)]
+pub struct ConsoleCommand {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub help: &'static str,
+    /// Completion candidates per argument position
+    completions: Vec<Vec<String>>,
+    handler: ConsoleHandler,
+}
+
+impl ConsoleCommand {
+    pub fn new(name: &'static str, handler: ConsoleHandler) -> Self {
+        Self {
+            name,
+            usage: name,
+            help: "",
+            completions: Vec::new(),
+            handler,
+        }
+    }
+
+    pub fn usage(mut self, usage: &'static str) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    pub fn help(mut self, help: &'static str) -> Self {
+        self.help = help;
+        self
+    }
+
+    /// Sets the values offered by Tab completion for argument `index`.
+    pub fn completions<S: Into<String>>(
+        mut self,
+        index: usize,
+        values: impl IntoIterator<Item = S>,
+    ) -> Self {
+        if self.completions.len() <= index {
+            self.completions.resize(index + 1, Vec::new());
+        }
+        self.completions[index] = values.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// All registered console commands, by name
+#[derive(Resource, Default)]
+pub struct ConsoleRegistry {
+    commands: BTreeMap<&'static str, ConsoleCommand>,
+}
+
+impl ConsoleRegistry {
+    pub fn register(&mut self, command: ConsoleCommand) {
+        if self.commands.insert(command.name, command).is_some() {
+            warn!(target: LOG_CLIENT, "Console command registered twice, keeping the last one");
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.get(name)
+    }
+
+    pub fn commands(&self) -> impl Iterator<Item = &ConsoleCommand> {
+        self.commands.values()
+    }
+
+    /// Completes the last word of `input`. Returns the completed line if exactly one candidate
+    /// matches (or the longest common prefix of several), together with all candidates.
+    pub fn complete(&self, input: &str) -> (Option<String>, Vec<String>) {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let ends_with_space = input.ends_with(char::is_whitespace);
+        // Word being completed and its position (0 = command name)
+        let (position, partial) = match (words.len(), ends_with_space) {
+            (0, _) => (0, ""),
+            (n, true) => (n, ""),
+            (n, false) => (n - 1, words[n - 1]),
+        };
+
+        let candidates: Vec<String> = if position == 0 {
+            self.commands.keys().map(|name| name.to_string()).collect()
+        } else {
+            self.get(words[0])
+                .and_then(|command| command.completions.get(position - 1))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        let lower = partial.to_lowercase();
+        let matches: Vec<String> = candidates
+            .into_iter()
+            .filter(|candidate| candidate.to_lowercase().starts_with(&lower))
+            .collect();
+
+        let completed = match matches.as_slice() {
+            [] => None,
+            [single] => Some(format!("{single} ")),
+            [first, rest @ ..] => {
+                let prefix = rest.iter().fold(first.clone(), |prefix, candidate| {
+                    prefix
+                        .chars()
+                        .zip(candidate.chars())
+                        .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+                        .map(|(a, _)| a)
+                        .collect()
+                });
+                (prefix.len() > partial.len()).then_some(prefix)
+            }
+        };
+
+        let line = completed.map(|word| {
+            let head = &input[..input.len() - partial.len()];
+            format!("{head}{word}")
+        });
+        (line, matches)
+    }
+}
+
+/// Extension trait for registering console commands from any plugin
+pub trait ConsoleAppExt {
+    fn register_console_command(&mut self, command: ConsoleCommand) -> &mut Self;
+}
+
+impl ConsoleAppExt for App {
+    fn register_console_command(&mut self, command: ConsoleCommand) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<ConsoleRegistry>()
+            .register(command);
+        self
+    }
+}
+
+/// Parsed arguments of a console command (without the command name)
+pub struct ConsoleArgs {
+    args: Vec<String>,
+}
+
+impl ConsoleArgs {
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// Returns argument `index` or an error naming the missing argument.
+    pub fn required(&self, index: usize, name: &str) -> Result<&str, String> {
+        self.get(index)
+            .ok_or_else(|| format!("missing argument <{name}>"))
+    }
+
+    /// Parses argument `index` into `T`.
+    pub fn parse<T: FromStr>(&self, index: usize, name: &str) -> Result<T, String> {
+        let value = self.required(index, name)?;
+        value
+            .parse()
+            .map_err(|_| format!("invalid value for <{name}>: '{value}'"))
+    }
+}
+
+/// Splits a command line into words. Double quotes group words containing spaces.
+pub fn tokenize(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_word = false;
+
+    for c in line.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_word = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_word {
+                    words.push(std::mem::take(&mut current));
+                    has_word = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_word = true;
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quote".to_string());
+    }
+    if has_word {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+/// Kind of a console output line, used for colouring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleLineKind {
+    Input,
+    Output,
+    Error,
+    Log(tracing::Level),
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsoleLine {
+    pub kind: ConsoleLineKind,
+    pub text: String,
+}
+
+/// Output shown in the console, oldest first
+#[derive(Resource, Default)]
+pub struct ConsoleOutput {
+    lines: VecDeque<ConsoleLine>,
+}
+
+impl ConsoleOutput {
+    pub fn push(&mut self, kind: ConsoleLineKind, text: impl Into<String>) {
+        if self.lines.len() >= MAX_OUTPUT_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(ConsoleLine {
+            kind,
+            text: text.into(),
+        });
+    }
+
+    pub fn print(&mut self, text: impl Into<String>) {
+        self.push(ConsoleLineKind::Output, text);
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(ConsoleLineKind::Error, text);
+    }
+
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = &ConsoleLine> {
+        self.lines.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+/// Previously executed command lines, persisted under the data directory
+#[derive(Resource, Default)]
+pub struct ConsoleHistory {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl ConsoleHistory {
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    fn push(&mut self, line: &str) {
+        if self.entries.last().map(String::as_str) == Some(line) {
+            return;
+        }
+        self.entries.push(line.to_string());
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.remove(0);
+        }
+        self.save();
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Err(err) = std::fs::write(path, self.entries.join("\n")) {
+            warn!(target: LOG_CLIENT, "Failed to save console history: {}", err);
+        }
+    }
+}
+
+fn load_console_history(mut history: ResMut<ConsoleHistory>, registry: Res<PathRegistry>) {
+    let path = registry
+        .get::<DataDir>()
+        .expect("DataDir is registered")
+        .join(HISTORY_FILE);
+
+    if let Ok(contents) = std::fs::read_to_string(&path) {
+        history.entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+    history.path = Some(path);
+}
+
+/// Runs a console command line: echoes it, records it in the history and executes it.
+pub fn execute_console_command(world: &mut World, line: &str) {
+    let line = line.trim();
+    if line.is_empty() {
+        return;
+    }
+
+    world
+        .resource_mut::<ConsoleOutput>()
+        .push(ConsoleLineKind::Input, format!("> {line}"));
+    world.resource_mut::<ConsoleHistory>().push(line);
+
+    let words = match tokenize(line) {
+        Ok(words) => words,
+        Err(err) => {
+            world.resource_mut::<ConsoleOutput>().error(err);
+            return;
+        }
+    };
+    let Some((name, args)) = words.split_first() else {
+        return;
+    };
+
+    let Some(handler) = world
+        .resource::<ConsoleRegistry>()
+        .get(name)
+        .map(|command| command.handler)
+    else {
+        world
+            .resource_mut::<ConsoleOutput>()
+            .error(format!("unknown command '{name}', try 'help'"));
+        return;
+    };
+
+    let args = ConsoleArgs {
+        args: args.to_vec(),
+    };
+    if let Err(err) = handler(world, &args) {
+        let usage = world
+            .resource::<ConsoleRegistry>()
+            .get(name)
+            .map(|command| command.usage)
+            .unwrap_or_default();
+        let mut output = world.resource_mut::<ConsoleOutput>();
+        output.error(err);
+        output.error(format!("usage: {usage}"));
+    }
+}
+
+// --- Built-in commands ---
+
+const GAME_STATES: [GameState; 4] = [
+    GameState::Splashscreen,
+    GameState::MainMenu,
+    GameState::ConnectingToServer,
+    GameState::InGame,
+];
+
+fn help_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let lines: Vec<String> = {
+        let registry = world.resource::<ConsoleRegistry>();
+        match args.get(0) {
+            Some(name) => {
+                let command = registry
+                    .get(name)
+                    .ok_or_else(|| format!("unknown command '{name}'"))?;
+                vec![format!("{} - {}", command.usage, command.help)]
+            }
+            None => registry
+                .commands()
+                .map(|command| format!("{:<40} {}", command.usage, command.help))
+                .collect(),
+        }
+    };
+
+    let mut output = world.resource_mut::<ConsoleOutput>();
+    for line in lines {
+        output.print(line);
+    }
+    Ok(())
+}
+
+fn clear_command(world: &mut World, _args: &ConsoleArgs) -> Result<(), String> {
+    world.resource_mut::<ConsoleOutput>().clear();
+    Ok(())
+}
+
+fn state_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let name = args.required(0, "GameState")?;
+    let state = GAME_STATES
+        .into_iter()
+        .find(|state| format!("{state:?}").eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("unknown game state '{name}'"))?;
+    check_state_transition(world, &state)?;
+
+    world
+        .resource_mut::<NextState<GameState>>()
+        .set(state.clone());
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Switching to {state:?}"));
+    Ok(())
+}
+
+/// Only allows the transitions the game itself makes: the in-game scene expects a running
+/// (embedded) server and a connected client.
+fn check_state_transition(world: &World, to: &GameState) -> Result<(), String> {
+    let from = world.resource::<State<GameState>>().get();
+    let has_server = world.contains_resource::<game_server::ServerHandle>();
+    let connected = world
+        .get_resource::<State<ClientState>>()
+        .is_some_and(|state| *state.get() == ClientState::Connected);
+
+    let allowed = match (from, to) {
+        (from, to) if from == to => return Err(format!("already in {to:?}")),
+        (GameState::Splashscreen, GameState::MainMenu) => true,
+        (GameState::MainMenu, GameState::ConnectingToServer) => has_server,
+        (GameState::ConnectingToServer, GameState::MainMenu) => true,
+        (GameState::ConnectingToServer, GameState::InGame) => has_server && connected,
+        (GameState::InGame, GameState::MainMenu) => true,
+        _ => false,
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(format!(
+            "can't switch from {from:?} to {to:?} (server running: {has_server}, connected: {connected})"
+        ))
+    }
+}
+
+fn camera_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let name = args.required(0, "mode")?;
+    let mode =
+        CameraMode::from_name(name).ok_or_else(|| format!("unknown camera mode '{name}'"))?;
+
+    let player = match args.get(1) {
+        Some(_) => args.parse::<usize>(1, "player")?,
+        None => 1,
+    };
+    let players = world.resource::<LocalPlayers>().count();
+    if player == 0 || player > players {
+        return Err(format!("no player {player}, {players} playing"));
+    }
+
+    let mut cameras = world.query_filtered::<(), With<InGameCamera>>();
+    if cameras.iter(world).next().is_none() {
+        return Err("no in-game camera, enter the game first".to_string());
+    }
+
+    world.trigger(SetCameraMode::for_slot(mode, LocalPlayerSlot(player - 1)));
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Camera mode of player {player}: {mode:?}"));
+    Ok(())
+}
+
+fn shake_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let amount = args.parse::<f32>(0, "trauma")?;
+
+    let trauma = match args.get(1) {
+        Some(_) => {
+            let player = args.parse::<usize>(1, "player")?;
+            let players = world.resource::<LocalPlayers>().count();
+            if player == 0 || player > players {
+                return Err(format!("no player {player}, {players} playing"));
+            }
+            CameraTrauma::for_slot(LocalPlayerSlot(player - 1), amount)
+        }
+        None => CameraTrauma::all(amount),
+    };
+
+    world.trigger(trauma);
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Added {amount} camera trauma"));
+    Ok(())
+}
+
+fn cutscene_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let path = args.required(0, "path")?.to_string();
+
+    let mut cameras = world.query_filtered::<(), With<InGameCamera>>();
+    if cameras.iter(world).next().is_none() {
+        return Err("no in-game camera, enter the game first".to_string());
+    }
+
+    if path == "stop" {
+        world.trigger(StopCutscene);
+        world
+            .resource_mut::<ConsoleOutput>()
+            .print("Cutscene stopped");
+        return Ok(());
+    }
+
+    let playback = match args.get(1) {
+        None | Some("once") => PathPlayback::Once,
+        Some("loop") => PathPlayback::Loop,
+        Some("pingpong") => PathPlayback::PingPong,
+        Some(other) => return Err(format!("unknown playback '{other}'")),
+    };
+    let handle = world.resource::<AssetServer>().load(path.clone());
+    world.trigger(PlayCutscene {
+        path: handle,
+        playback,
+    });
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Playing cutscene {path} ({playback:?})"));
+    Ok(())
+}
+
+fn tp_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    let position = Vec3::new(
+        args.parse(0, "x")?,
+        args.parse(1, "y")?,
+        args.parse(2, "z")?,
+    );
+
+    let connected = world
+        .get_resource::<State<ClientState>>()
+        .is_some_and(|state| *state.get() == ClientState::Connected);
+    if !connected {
+        return Err("not connected to a server".to_string());
+    }
+
+    // The server checks the role again, this only saves a request that would be refused
+    let hosting = world.contains_resource::<game_server::ServerHandle>();
+    let is_admin = world
+        .query_filtered::<Option<&PlayerRole>, With<LocalPlayer>>()
+        .iter(world)
+        .any(|role| {
+            let fallback = if hosting {
+                PlayerRole::Host
+            } else {
+                PlayerRole::Player
+            };
+            role.copied().unwrap_or(fallback).is_admin()
+        });
+    if !is_admin {
+        return Err("admin rights required".to_string());
+    }
+
+    world.commands().client_trigger(AdminTeleport { position });
+    world.flush();
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Requested teleport to {position}"));
+    Ok(())
+}
+
+fn log_command(world: &mut World, args: &ConsoleArgs) -> Result<(), String> {
+    if args.required(0, "level")? != "level" {
+        return Err("unknown subcommand".to_string());
+    }
+    let target = args.required(1, "target")?;
+    let level = args.required(2, "level")?;
+
+    let levels = world
+        .get_resource::<LogLevels>()
+        .ok_or("logging is not initialized")?
+        .clone();
+
+    if level.eq_ignore_ascii_case("reset") {
+        levels.reset(target);
+        world
+            .resource_mut::<ConsoleOutput>()
+            .print(format!("Log level of '{target}' reset"));
+        return Ok(());
+    }
+
+    let filter: LevelFilter = level
+        .parse()
+        .map_err(|_| format!("invalid level '{level}'"))?;
+    levels.set(target, filter);
+    world
+        .resource_mut::<ConsoleOutput>()
+        .print(format!("Log level of '{target}' set to {filter}"));
+    Ok(())
+}
+
+fn quit_command(world: &mut World, _args: &ConsoleArgs) -> Result<(), String> {
+    world.write_message(AppExit::Success);
+    Ok(())
+}
+
+// --- Log mirroring ---
+
+/// Receives log lines produced by [`DevConsoleLayer`]
+#[derive(Resource)]
+struct LogLineReceiver(Mutex<Receiver<ConsoleLine>>);
+
+/// Tracing layer that mirrors log events into the developer console.
+pub struct DevConsoleLayer {
+    sender: Sender<ConsoleLine>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for DevConsoleLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = LogLineVisitor::default();
+        event.record(&mut visitor);
+
+        let _ = self.sender.send(ConsoleLine {
+            kind: ConsoleLineKind::Log(*metadata.level()),
+            text: format!(
+                "{:>5} {}: {}{}",
+                metadata.level(),
+                metadata.target(),
+                visitor.message,
+                visitor.fields
+            ),
+        });
+    }
+}
+
+#[derive(Default)]
+struct LogLineVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for LogLineVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields
+                .push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// Creates the tracing layer mirroring logs into the console. Call while setting up logging.
+pub fn dev_console_layer(world: &mut World) -> DevConsoleLayer {
+    let (sender, receiver) = channel();
+    world.insert_resource(LogLineReceiver(Mutex::new(receiver)));
+    DevConsoleLayer { sender }
+}
+
+fn forward_log_lines(receiver: Option<Res<LogLineReceiver>>, mut output: ResMut<ConsoleOutput>) {
+    let Some(receiver) = receiver else {
+        return;
+    };
+    let Ok(receiver) = receiver.0.lock() else {
+        return;
+    };
+    for line in receiver.try_iter() {
+        output.push(line.kind, line.text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noop(_: &mut World, _: &ConsoleArgs) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn registry() -> ConsoleRegistry {
+        let mut registry = ConsoleRegistry::default();
+        registry.register(ConsoleCommand::new("clear", noop));
+        registry.register(
+            ConsoleCommand::new("camera", noop)
+                .completions(0, ["FirstPerson", "ThirdPerson", "FreeFly"]),
+        );
+        registry.register(ConsoleCommand::new("cutscene", noop).completions(1, ["once", "loop"]));
+        registry
+            .register(ConsoleCommand::new("state", noop).completions(0, ["InGame", "InGameMenu"]));
+        registry
+    }
+
+    #[test]
+    fn tokenize_splits_on_whitespace() {
+        assert_eq!(
+            tokenize("  tp 1.5\t-2  3 "),
+            Ok(vec![
+                "tp".to_string(),
+                "1.5".to_string(),
+                "-2".to_string(),
+                "3".to_string()
+            ])
+        );
+        assert_eq!(tokenize("   "), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn tokenize_groups_quoted_words() {
+        assert_eq!(
+            tokenize(r#"cutscene "intro path.campath.json" loop"#),
+            Ok(vec![
+                "cutscene".to_string(),
+                "intro path.campath.json".to_string(),
+                "loop".to_string()
+            ])
+        );
+        // Quotes inside a word join it with the quoted part, empty quotes are an empty word
+        assert_eq!(
+            tokenize(r#"a"b c"d """#),
+            Ok(vec!["ab cd".to_string(), String::new()])
+        );
+    }
+
+    #[test]
+    fn tokenize_rejects_unterminated_quotes() {
+        assert_eq!(
+            tokenize(r#"cutscene "intro"#),
+            Err("unterminated quote".to_string())
+        );
+    }
+
+    #[test]
+    fn complete_command_names() {
+        let registry = registry();
+        assert_eq!(
+            registry.complete("cam"),
+            (Some("camera ".to_string()), vec!["camera".to_string()])
+        );
+        // Several matches without a longer common prefix complete nothing
+        let (line, matches) = registry.complete("c");
+        assert_eq!(line, None);
+        assert_eq!(matches, ["camera", "clear", "cutscene"]);
+        assert_eq!(registry.complete("x"), (None, Vec::new()));
+    }
+
+    #[test]
+    fn complete_arguments_case_insensitively() {
+        let registry = registry();
+        assert_eq!(
+            registry.complete("camera fi"),
+            (
+                Some("camera FirstPerson ".to_string()),
+                vec!["FirstPerson".to_string()]
+            )
+        );
+        // Longest common prefix of several candidates
+        let (line, matches) = registry.complete("state i");
+        assert_eq!(line, Some("state InGame".to_string()));
+        assert_eq!(matches, ["InGame", "InGameMenu"]);
+        // All candidates of the next argument after a space
+        let (line, matches) = registry.complete("cutscene intro.campath.json ");
+        assert_eq!(line, None);
+        assert_eq!(matches, ["once", "loop"]);
+    }
+
+    #[test]
+    fn complete_nothing_without_candidates() {
+        let registry = registry();
+        assert_eq!(registry.complete("clear "), (None, Vec::new()));
+        assert_eq!(registry.complete("unknown ar"), (None, Vec::new()));
+    }
+}
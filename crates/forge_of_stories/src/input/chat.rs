@@ -0,0 +1,111 @@
+//! Chat input handling.
+//!
+//! Enter opens the chat input line, Enter sends, ESC cancels. While typing the cursor is
+//! released and gameplay input (movement, camera, menu) is suppressed.
+
+use crate::GameState;
+use crate::chat::{
+    ChatLog, ChatMessage, ChatRateLimiter, ChatRejection, MAX_CHAT_MESSAGE_LEN,
+    sanitize_chat_message,
+};
+use crate::ui::components::{
+    ChatInputState, InGameMenuState, console_allows_input, photo_mode_allows_input,
+};
+use crate::ui::scenes::in_game::cameras::CursorState;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+
+/// Plugin for chat text input.
+pub struct ChatInputPlugin;
+
+impl Plugin for ChatInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChatInputState>()
+            .add_systems(
+                Update,
+                handle_chat_input
+                    .after(super::menu::handle_menu_toggle)
+                    .run_if(console_allows_input)
+                    .run_if(photo_mode_allows_input)
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnExit(GameState::InGame), close_chat_input);
+    }
+}
+
+/// Reads keyboard text input into the chat buffer and sends finished messages.
+#[allow(clippy::too_many_arguments)]
+fn handle_chat_input(
+    mut commands: Commands,
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut chat: ResMut<ChatInputState>,
+    mut cursor: ResMut<CursorState>,
+    mut log: ResMut<ChatLog>,
+    mut rate_limiter: Local<ChatRateLimiter>,
+    mut previous_cursor: Local<Option<CursorState>>,
+    menu: Res<InGameMenuState>,
+    time: Res<Time>,
+) {
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        if !chat.is_open() {
+            if event.key_code == KeyCode::Enter && menu.is_closed() {
+                chat.open();
+                *previous_cursor = Some(cursor.clone());
+                *cursor = CursorState::FREE;
+            }
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let text = chat.close();
+                if let Some(previous) = previous_cursor.take() {
+                    *cursor = previous;
+                }
+
+                let text = match sanitize_chat_message(&text) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                };
+
+                match rate_limiter.try_send(time.elapsed_secs()) {
+                    Ok(()) => commands.client_trigger(ChatMessage { text }),
+                    Err(ChatRejection::RateLimited) => {
+                        log.push_system("You are sending messages too quickly", time.elapsed_secs())
+                    }
+                    Err(ChatRejection::Empty) => {}
+                }
+            }
+            Key::Escape => {
+                chat.close();
+                if let Some(previous) = previous_cursor.take() {
+                    *cursor = previous;
+                }
+            }
+            Key::Backspace => {
+                chat.buffer.pop();
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                for c in text.chars().filter(|c| !c.is_control()) {
+                    if chat.buffer.chars().count() >= MAX_CHAT_MESSAGE_LEN {
+                        break;
+                    }
+                    chat.buffer.push(c);
+                }
+            }
+        }
+    }
+}
+
+fn close_chat_input(mut chat: ResMut<ChatInputState>) {
+    chat.close();
+}
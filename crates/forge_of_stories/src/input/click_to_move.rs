@@ -0,0 +1,261 @@
+//! Click-to-move input.
+//!
+//! In pan-orbit mode a left click (press and release without dragging the orbit) on a
+//! `GroundPlane` sends a [`PlayerMoveTarget`] to the server, which steers the player there and
+//! turns it into the walking direction. A ring marks the destination until the player arrives
+//! or the target is cancelled by input that walks the player.
+
+use crate::GameState;
+use crate::input::player::stick_direction;
+use crate::networking::LocalPlayer;
+use crate::ui::components::{gameplay_input_allowed, menu_allows_input};
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CameraInputUnlocked, CursorState, InGameCamera, LocalPlayerSlot, LocalPlayers,
+};
+use crate::utils::cleanup;
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_replicon::prelude::*;
+use game_server::GroundPlane;
+use serde::{Deserialize, Serialize};
+
+/// Cursor travel (pixels) up to which a press and release count as a click, not an orbit drag
+const CLICK_MAX_DRAG: f32 = 6.0;
+/// Horizontal distance (m) at which the destination counts as reached
+const ARRIVAL_RADIUS: f32 = 0.3;
+/// Height of the marker above the ground, avoids z-fighting
+const MARKER_LIFT: f32 = 0.02;
+
+/// Plugin for click-to-move input and the destination marker
+pub struct ClickToMovePlugin;
+
+impl Plugin for ClickToMovePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MoveTarget>()
+            .add_systems(OnEnter(GameState::InGame), spawn_marker)
+            .add_systems(
+                Update,
+                (
+                    pick_move_target
+                        .run_if(in_state(ClientState::Connected))
+                        .run_if(|cursor: Res<CursorState>| cursor.visible)
+                        .run_if(menu_allows_input)
+                        .run_if(gameplay_input_allowed),
+                    clear_reached_move_target,
+                    cancel_move_target
+                        .run_if(in_state(ClientState::Connected))
+                        .run_if(gameplay_input_allowed),
+                    update_marker,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                OnExit(GameState::InGame),
+                (cleanup::<MoveTargetMarker>, clear_move_target),
+            );
+    }
+}
+
+/// Destination for the server-side steering of the sending player.
+///
+/// `None` cancels the current target. The server stops on arrival or when movement input
+/// (`PlayerMovement` with a non-zero direction) overrides it.
+#[derive(Event, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct PlayerMoveTarget {
+    pub target: Option<Vec3>,
+}
+
+/// Destination of the local player as sent to the server
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+pub struct MoveTarget(pub Option<Vec3>);
+
+/// Ring on the ground at the current destination
+#[derive(Component)]
+struct MoveTargetMarker;
+
+/// Cursor position where the left button went down
+#[derive(Default)]
+struct ClickStart(Option<Vec2>);
+
+fn spawn_marker(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Annulus::new(0.35, 0.45))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.85, 0.3, 0.8),
+            emissive: LinearRgba::rgb(1.0, 0.7, 0.2),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+        Visibility::Hidden,
+        MoveTargetMarker,
+        Name::new("Move Target Marker"),
+    ));
+}
+
+/// Left click on the ground in pan-orbit mode sets the destination.
+#[allow(clippy::too_many_arguments)]
+fn pick_move_target(
+    mut commands: Commands,
+    mut click_start: Local<ClickStart>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<
+        (
+            &Camera,
+            &GlobalTransform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+        ),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+    grounds: Query<(), With<GroundPlane>>,
+    interactions: Query<&Interaction>,
+    mut ray_cast: MeshRayCast,
+    mut move_target: ResMut<MoveTarget>,
+) {
+    // The mouse steers the primary player
+    let Some((camera, camera_transform, active_mode)) = LocalPlayerSlot::PRIMARY.find(
+        cameras
+            .iter()
+            .map(|(camera, transform, mode, slot)| ((camera, transform, mode), slot)),
+    ) else {
+        return;
+    };
+    if !active_mode.mode.uses_pan_orbit() {
+        click_start.0 = None;
+        return;
+    }
+    let Some(cursor) = window.iter().next().and_then(Window::cursor_position) else {
+        return;
+    };
+
+    if mouse.just_pressed(MouseButton::Left) {
+        // Clicks on UI elements are not meant for the world
+        let over_ui = interactions
+            .iter()
+            .any(|interaction| *interaction != Interaction::None);
+        click_start.0 = (!over_ui).then_some(cursor);
+    }
+    if !mouse.just_released(MouseButton::Left) {
+        return;
+    }
+    let Some(start) = click_start.0.take() else {
+        return;
+    };
+    // Dragging orbits the camera
+    if start.distance(cursor) > CLICK_MAX_DRAG {
+        return;
+    }
+
+    // In split screen the primary camera only covers part of the window
+    if !camera
+        .logical_viewport_rect()
+        .is_some_and(|viewport| viewport.contains(cursor))
+    {
+        return;
+    }
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+    // Only the ground is a destination, clicks on anything in front of it are ignored
+    let Some(point) = ray_cast
+        .cast_ray(ray, &MeshRayCastSettings::default())
+        .first()
+        .filter(|(entity, _)| grounds.contains(*entity))
+        .map(|(_, hit)| hit.point)
+    else {
+        return;
+    };
+
+    move_target.0 = Some(point);
+    commands.client_trigger(PlayerMoveTarget {
+        target: Some(point),
+    });
+}
+
+/// Drops the destination once the player arrived; the server stops by itself.
+fn clear_reached_move_target(
+    local_players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    mut move_target: ResMut<MoveTarget>,
+) {
+    let Some(target) = move_target.0 else {
+        return;
+    };
+
+    let arrived = LocalPlayerSlot::PRIMARY
+        .find(local_players.iter())
+        .is_some_and(|player| (player.translation - target).with_y(0.0).length() < ARRIVAL_RADIUS);
+    if arrived {
+        move_target.0 = None;
+    }
+}
+
+/// Cancels the destination when the primary player is walked by hand.
+///
+/// Only input that `send_player_input` turns into movement counts: in the pan-orbit modes WASD
+/// and the left stick pan the camera and leave the destination alone.
+fn cancel_move_target(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    cursor: Res<CursorState>,
+    local_players: Res<LocalPlayers>,
+    gamepads: Query<&Gamepad>,
+    cameras: Query<(&ActiveCameraMode, &LocalPlayerSlot), With<InGameCamera>>,
+    mut move_target: ResMut<MoveTarget>,
+) {
+    if move_target.0.is_none() {
+        return;
+    }
+
+    let walks_player = *cursor == CursorState::LOCKED
+        && LocalPlayerSlot::PRIMARY
+            .find(cameras.iter())
+            .is_some_and(|active_mode| {
+                active_mode.mode.controls_player() && !active_mode.mode.uses_pan_orbit()
+            });
+    if !walks_player {
+        return;
+    }
+
+    let keys = keyboard.any_pressed([KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD]);
+    // Alone the keyboard player also walks with any gamepad's stick
+    let stick = !local_players.is_split()
+        && gamepads
+            .iter()
+            .any(|gamepad| stick_direction(gamepad) != Vec2::ZERO);
+    if keys || stick {
+        move_target.0 = None;
+        commands.client_trigger(PlayerMoveTarget { target: None });
+    }
+}
+
+fn update_marker(
+    time: Res<Time>,
+    move_target: Res<MoveTarget>,
+    mut markers: Query<(&mut Transform, &mut Visibility), With<MoveTargetMarker>>,
+) {
+    for (mut transform, mut visibility) in &mut markers {
+        let Some(target) = move_target.0 else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        visibility.set_if_neq(Visibility::Visible);
+        transform.translation = target + Vec3::Y * MARKER_LIFT;
+        // Gentle pulse so the marker reads as a destination
+        let pulse = 1.0 + (time.elapsed_secs() * 4.0).sin() * 0.08;
+        transform.scale = Vec3::splat(pulse);
+    }
+}
+
+fn clear_move_target(mut move_target: ResMut<MoveTarget>) {
+    move_target.0 = None;
+}
@@ -0,0 +1,122 @@
+//! Developer console input handling.
+//!
+//! ` toggles the console, Enter runs the typed command, Tab completes, Up/Down browse the
+//! history and ESC closes. While the console is open the cursor is released and gameplay
+//! input is suppressed.
+
+use crate::console::{ConsoleHistory, ConsoleRegistry, execute_console_command};
+use crate::ui::components::{ChatInputState, ConsoleState};
+use crate::ui::scenes::in_game::cameras::CursorState;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+
+/// Plugin for developer console input.
+pub struct ConsoleInputPlugin;
+
+impl Plugin for ConsoleInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ConsoleState>().add_systems(
+            Update,
+            handle_console_input.after(super::menu::handle_menu_toggle),
+        );
+    }
+}
+
+/// Reads keyboard input into the console line and runs finished commands.
+#[allow(clippy::too_many_arguments)]
+fn handle_console_input(
+    mut commands: Commands,
+    mut keyboard_events: MessageReader<KeyboardInput>,
+    mut console: ResMut<ConsoleState>,
+    mut cursor: ResMut<CursorState>,
+    mut previous_cursor: Local<Option<CursorState>>,
+    chat: Res<ChatInputState>,
+    registry: Res<ConsoleRegistry>,
+    history: Res<ConsoleHistory>,
+) {
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        if event.key_code == KeyCode::Backquote {
+            if console.is_open() {
+                console.close();
+                if let Some(previous) = previous_cursor.take() {
+                    *cursor = previous;
+                }
+            } else if !chat.is_open() {
+                console.open();
+                *previous_cursor = Some(cursor.clone());
+                *cursor = CursorState::FREE;
+            }
+            continue;
+        }
+
+        if !console.is_open() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Enter => {
+                let line = console.take_buffer();
+                commands.queue(move |world: &mut World| execute_console_command(world, &line));
+            }
+            Key::Escape => {
+                console.close();
+                if let Some(previous) = previous_cursor.take() {
+                    *cursor = previous;
+                }
+            }
+            Key::Backspace => {
+                console.buffer.pop();
+                console.completions.clear();
+            }
+            Key::Tab => {
+                let (completed, candidates) = registry.complete(&console.buffer);
+                if let Some(line) = completed {
+                    console.buffer = line;
+                }
+                console.completions = if candidates.len() > 1 {
+                    candidates
+                } else {
+                    Vec::new()
+                };
+            }
+            Key::ArrowUp => {
+                let entries = history.entries();
+                if entries.is_empty() {
+                    continue;
+                }
+                let index = match console.history_index {
+                    Some(index) => index.saturating_sub(1),
+                    None => entries.len() - 1,
+                };
+                console.history_index = Some(index);
+                console.buffer = entries[index].clone();
+            }
+            Key::ArrowDown => {
+                let Some(index) = console.history_index else {
+                    continue;
+                };
+                let entries = history.entries();
+                if index + 1 < entries.len() {
+                    console.history_index = Some(index + 1);
+                    console.buffer = entries[index + 1].clone();
+                } else {
+                    console.take_buffer();
+                }
+            }
+            _ => {
+                let Some(text) = &event.text else {
+                    continue;
+                };
+                console
+                    .buffer
+                    .extend(text.chars().filter(|c| !c.is_control()));
+                console.completions.clear();
+            }
+        }
+    }
+}
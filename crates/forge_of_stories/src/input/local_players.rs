@@ -0,0 +1,144 @@
+//! Local split-screen players.
+//!
+//! Pressing Start on an unassigned gamepad joins another local player (up to four), Select
+//! leaves again; a disconnected gamepad leaves as well. The server spawns or removes a player
+//! for this client, which arrives as another `LocalPlayer`.
+//! Gamepad players steer with the left stick and jump with the south button (A/Cross); their
+//! movement is sent as [`LocalPlayerMovement`] tagged with their slot.
+
+use crate::GameState;
+use crate::app::LOG_CLIENT;
+use crate::input::player::{player_movement, stick_direction};
+use crate::networking::LocalPlayer;
+use crate::ui::components::{gameplay_input_allowed, menu_allows_input, photo_mode_allows_input};
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, InGameCamera, LocalPlayerSlot, LocalPlayers, PlayerInputDevice,
+};
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use game_server::PlayerMovement;
+use serde::{Deserialize, Serialize};
+
+/// Plugin for joining, leaving and steering additional local players
+pub struct LocalPlayersPlugin;
+
+impl Plugin for LocalPlayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (
+                    join_or_leave.run_if(photo_mode_allows_input),
+                    leave_disconnected,
+                )
+                    .chain(),
+                send_gamepad_input
+                    .run_if(menu_allows_input)
+                    .run_if(gameplay_input_allowed),
+            )
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(ClientState::Connected)),
+        );
+    }
+}
+
+/// Asks the server for another player controlled by this client
+#[derive(Event, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct JoinLocalPlayer;
+
+/// Removes the additional player in `slot`; the players behind it move up one slot
+#[derive(Event, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct LeaveLocalPlayer {
+    pub slot: usize,
+}
+
+/// Movement of an additional player. The primary player keeps sending `PlayerMovement`.
+#[derive(Event, Serialize, Deserialize)]
+pub struct LocalPlayerMovement {
+    pub slot: usize,
+    pub movement: PlayerMovement,
+}
+
+/// Start on an unassigned gamepad joins, Select on an assigned one leaves.
+fn join_or_leave(
+    mut commands: Commands,
+    mut local_players: ResMut<LocalPlayers>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    for (entity, gamepad) in &gamepads {
+        let device = PlayerInputDevice::Gamepad(entity);
+        match local_players.slot_of(device) {
+            None if gamepad.just_pressed(GamepadButton::Start) => {
+                // Ignored once all slots are taken
+                if let Some(slot) = local_players.join(device) {
+                    info!(target: LOG_CLIENT, "Local player {} joined", slot.0 + 1);
+                    commands.client_trigger(JoinLocalPlayer);
+                }
+            }
+            Some(slot) if gamepad.just_pressed(GamepadButton::Select) => {
+                if local_players.leave(slot) {
+                    info!(target: LOG_CLIENT, "Local player {} left", slot.0 + 1);
+                    commands.client_trigger(LeaveLocalPlayer { slot: slot.0 });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Players whose gamepad was disconnected leave the game.
+fn leave_disconnected(
+    mut commands: Commands,
+    mut local_players: ResMut<LocalPlayers>,
+    mut disconnected: RemovedComponents<Gamepad>,
+) {
+    for entity in disconnected.read() {
+        let Some(slot) = local_players.slot_of(PlayerInputDevice::Gamepad(entity)) else {
+            continue;
+        };
+        if local_players.leave(slot) {
+            info!(target: LOG_CLIENT, "Local player {} left (gamepad disconnected)", slot.0 + 1);
+            commands.client_trigger(LeaveLocalPlayer { slot: slot.0 });
+        }
+    }
+}
+
+/// Left stick movement and jumping of the gamepad players, relative to their cameras.
+#[allow(clippy::type_complexity)]
+fn send_gamepad_input(
+    mut commands: Commands,
+    gamepads: Query<&Gamepad>,
+    players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    cameras: Query<
+        (
+            &Transform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
+        ),
+        (With<InGameCamera>, Without<LocalPlayer>),
+    >,
+) {
+    for (camera_transform, active_mode, slot, device) in &cameras {
+        let PlayerInputDevice::Gamepad(gamepad) = *device else {
+            continue;
+        };
+        let (Ok(gamepad), Some(player)) = (gamepads.get(gamepad), slot.find(players.iter())) else {
+            continue;
+        };
+        if !active_mode.mode.controls_player() {
+            continue;
+        }
+
+        commands.client_trigger(LocalPlayerMovement {
+            slot: slot.0,
+            movement: player_movement(
+                camera_transform,
+                active_mode.mode,
+                player,
+                stick_direction(gamepad),
+                gamepad.pressed(GamepadButton::South),
+            ),
+        });
+    }
+}
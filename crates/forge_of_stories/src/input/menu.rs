@@ -1,10 +1,17 @@
 //! Menu input handling.
 //!
-//! Handles ESC key to toggle the in-game menu and manage cursor state.
+//! Handles ESC key to toggle the in-game menu (or close the full-screen map) and manage
+//! cursor state.
 
 use crate::GameState;
-use crate::ui::components::InGameMenuState;
-use crate::ui::scenes::in_game::cameras::CursorState;
+use crate::ui::components::{
+    InGameMenuState, MapViewState, chat_allows_input, console_allows_input, photo_mode_allows_input,
+};
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CursorState, InGameCamera, LocalPlayerSlot,
+};
+use crate::ui::scenes::in_game::minimap::map_cursor_state;
+use crate::ui::scenes::in_game::photo_mode::handle_photo_input;
 use bevy::prelude::*;
 
 /// Plugin for menu input handling.
@@ -16,18 +23,33 @@ impl Plugin for MenuInputPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            handle_menu_toggle.run_if(in_state(GameState::InGame)),
+            handle_menu_toggle
+                .run_if(in_state(GameState::InGame))
+                .run_if(chat_allows_input)
+                .run_if(console_allows_input)
+                // ESC leaves photo mode instead
+                .run_if(photo_mode_allows_input)
+                .before(handle_photo_input),
         );
     }
 }
 
 /// Handles ESC key to toggle the in-game menu.
-fn handle_menu_toggle(
+pub(super) fn handle_menu_toggle(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut cursor: ResMut<CursorState>,
     mut menu: ResMut<InGameMenuState>,
+    mut map: ResMut<MapViewState>,
+    cameras: Query<(&ActiveCameraMode, &LocalPlayerSlot), With<InGameCamera>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
+        // ESC closes the full-screen map first
+        if map.is_open() {
+            map.set_closed();
+            *cursor = map_cursor_state(&map, &cameras);
+            return;
+        }
+
         menu.toggle();
         *cursor = if menu.is_open() {
             CursorState::FREE
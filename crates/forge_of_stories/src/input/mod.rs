@@ -2,7 +2,11 @@
 //!
 //! This module consolidates all input handling:
 //! - **player.rs** - Player movement input (WASD, Space) → sent to server
+//! - **click_to_move.rs** - Click on the ground in pan-orbit mode → move target sent to server
+//! - **local_players.rs** - Gamepad join/leave and movement of split-screen players → sent to server
 //! - **menu.rs** - Menu toggling (ESC) → local UI state changes
+//! - **chat.rs** - Chat text input (Enter) → sent to server
+//! - **console.rs** - Developer console input (`) → local command execution
 //! - **camera.rs** - Camera controls (handled by camera systems)
 //!
 //! ## Architecture
@@ -11,6 +15,10 @@
 //! - Menu/UI input is handled locally and updates UI state
 //! - Camera input is kept with camera systems (specialized behavior)
 
+pub mod chat;
+pub mod click_to_move;
+pub mod console;
+pub mod local_players;
 pub mod menu;
 pub mod player;
 
@@ -21,6 +29,13 @@ pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((player::PlayerInputPlugin, menu::MenuInputPlugin));
+        app.add_plugins((
+            player::PlayerInputPlugin,
+            click_to_move::ClickToMovePlugin,
+            local_players::LocalPlayersPlugin,
+            menu::MenuInputPlugin,
+            chat::ChatInputPlugin,
+            console::ConsoleInputPlugin,
+        ));
     }
 }
@@ -1,53 +1,154 @@
 //! Player movement input handling.
 //!
-//! Sends player input (WASD, Space) to the server via bevy_replicon events.
+//! Sends player input (WASD, Space) to the server via bevy_replicon events. While playing
+//! alone, a gamepad's left stick and south button steer the player as well.
 
 use crate::GameState;
 use crate::networking::LocalPlayer;
-use crate::ui::scenes::in_game::cameras::{ActiveCameraMode, CursorState, InGameCamera};
+use crate::ui::components::gameplay_input_allowed;
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CameraMode, CursorState, InGameCamera, LocalPlayerSlot, LocalPlayers,
+    PlayerInputDevice,
+};
 use bevy::prelude::*;
 use bevy_replicon::prelude::*;
 use game_server::PlayerMovement;
 
+/// Left stick deflection below which a gamepad player stands still
+const STICK_DEAD_ZONE: f32 = 0.15;
+
 /// Plugin for player movement input.
 ///
-/// Registers the PlayerInput client event and sends input to the server
-/// when the player is in-game, connected, and the cursor is locked.
+/// Sends input to the server
+/// when the player is in-game, connected, the cursor is locked and chat is closed.
+/// Nothing is sent while the free-fly camera is active.
 pub struct PlayerInputPlugin;
 
 impl Plugin for PlayerInputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_client_event::<PlayerMovement>(Channel::Unreliable)
-            .add_systems(
-                Update,
-                send_player_input
-                    .run_if(in_state(GameState::InGame))
-                    .run_if(in_state(ClientState::Connected))
-                    .run_if(resource_equals(CursorState::LOCKED)),
-            );
+        app.add_systems(
+            Update,
+            send_player_input
+                .run_if(in_state(GameState::InGame))
+                .run_if(in_state(ClientState::Connected))
+                .run_if(resource_equals(CursorState::LOCKED))
+                .run_if(gameplay_input_allowed),
+        );
     }
 }
 
 /// System that collects WASD + Space input and sends it to the server.
+///
+/// Steers the player of the keyboard & mouse camera, together with any gamepad while playing
+/// alone; gamepad players of a split screen send their input from `local_players`.
+#[allow(clippy::type_complexity)]
 fn send_player_input(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
-    player: Single<(Entity, &Transform), With<LocalPlayer>>,
-    camera: Single<(&Transform, &ActiveCameraMode), (With<InGameCamera>, Without<LocalPlayer>)>,
+    local_players: Res<LocalPlayers>,
+    gamepads: Query<&Gamepad>,
+    players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    cameras: Query<
+        (
+            &Transform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
+        ),
+        (With<InGameCamera>, Without<LocalPlayer>),
+    >,
+    mut stopped: Local<bool>,
 ) {
+    let Some((camera_transform, active_mode, slot, _)) = cameras
+        .iter()
+        .find(|(.., device)| **device == PlayerInputDevice::KeyboardMouse)
+    else {
+        return;
+    };
+    let Some(player) = slot.find(players.iter()) else {
+        return;
+    };
+
+    // The free-fly camera uses WASD itself: stop the player once, then send nothing
+    if !active_mode.mode.controls_player() {
+        if !*stopped {
+            commands.client_trigger(PlayerMovement {
+                transform: *player,
+                movement: Vec3::ZERO,
+                jump: false,
+            });
+            *stopped = true;
+        }
+        return;
+    }
+    *stopped = false;
+
+    // x = rechts, y = vorwärts
+    let mut direction = Vec2::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        direction.y += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        direction.y -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        direction.x -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        direction.x += 1.0;
+    }
+    let mut jump = keyboard.pressed(KeyCode::Space);
+
+    // Alone the keyboard player's actions take any gamepad, so does the movement
+    if !local_players.is_split() {
+        for gamepad in &gamepads {
+            direction += stick_direction(gamepad);
+            jump |= gamepad.pressed(GamepadButton::South);
+        }
+    }
+
+    // Send input to server
+    commands.client_trigger(player_movement(
+        camera_transform,
+        active_mode.mode,
+        player,
+        direction,
+        jump,
+    ));
+}
+
+/// Left stick of a gamepad, zero inside the dead zone
+pub(super) fn stick_direction(gamepad: &Gamepad) -> Vec2 {
+    let stick = gamepad.left_stick();
+    if stick.length() < STICK_DEAD_ZONE {
+        Vec2::ZERO
+    } else {
+        stick
+    }
+}
+
+/// Builds the movement message for a player steered relative to its camera.
+///
+/// `direction` is the input in camera space (x = right, y = forward) with a length up to 1.
+pub(super) fn player_movement(
+    camera_transform: &Transform,
+    mode: CameraMode,
+    player: &Transform,
+    direction: Vec2,
+    jump: bool,
+) -> PlayerMovement {
     // TODO: Je anch kamera werden unterschiedlichen INput logiken aktiv und gesendet
     // wenn man in Fist Person ist kann man den Spiler direkt über WASD bewegen und die rotation des Spielers ist direkt von der Maus Abhängig
     // Wenn man in Thirdperson ist kann man den Spieler durch klicken auf dem bildschirm auf die richtige position bringen. Rotation wird automatisch gemacht.
     // man kann aber auch mit wasd bewegen hier wird auch der Spieler in die richtung rotiert in die man sich bewegbt.
     // in PAN Mode kann man die die Kamera frei vom Spieler durch WASD oder durch zeihen der Maus bewegen.
+    // Klicken auf den Boden im PAN Mode: siehe click_to_move.rs
     // if camera.1.mode == CameraMode::FirstPerson {
 
     // let mut transform = *player.1;
     // transform.rotation = camera.0.rotation;
     //
 
-    let camera_transform = camera.0;
-
     // Kamera forward/right Vektoren direkt verwenden
     // Projektion auf XZ-Ebene (Y auf 0 setzen) für horizontale Bewegung
     let camera_forward = Vec3::from(*camera_transform.forward());
@@ -60,34 +161,27 @@ fn send_player_input(
     let (_, yaw, _) = camera_transform.rotation.to_euler(EulerRot::YXZ);
     let player_rotation = Quat::from_rotation_y(yaw);
 
-    let mut movement = Vec3::ZERO;
-    if keyboard.pressed(KeyCode::KeyW) {
-        movement += forward;
-    }
-    if keyboard.pressed(KeyCode::KeyS) {
-        movement -= forward;
-    }
-    if keyboard.pressed(KeyCode::KeyA) {
-        movement -= right;
-    }
-    if keyboard.pressed(KeyCode::KeyD) {
-        movement += right;
-    }
-
-    // Normalisieren wenn Bewegung stattfindet
-    if movement.length() > 0.0 {
-        movement = movement.normalize();
-        info!("Camera yaw: {:.2}, Movement vector: {:?}", yaw.to_degrees(), movement);
-    }
+    // Auf Länge 1 begrenzen: Tasten diagonal nicht schneller, Sticks behalten Zwischenwerte
+    let direction = direction.clamp_length_max(1.0);
+    let movement = right * direction.x + forward * direction.y;
 
     // Transform mit Rotation erstellen (Position kommt vom Server)
-    let mut input_transform = *player.1;
-    input_transform.rotation = player_rotation;
+    let mut input_transform = *player;
+    input_transform.rotation = match mode {
+        // In ThirdPerson dreht sich der Spieler in Bewegungsrichtung und behält sonst seine Rotation
+        CameraMode::ThirdPerson => {
+            if movement.length() > 0.0 {
+                Quat::from_rotation_y(f32::atan2(-movement.x, -movement.z))
+            } else {
+                player.rotation
+            }
+        }
+        _ => player_rotation,
+    };
 
-    // Send input to server
-    commands.client_trigger(PlayerMovement {
+    PlayerMovement {
         transform: input_transform,
         movement,
-        jump: keyboard.pressed(KeyCode::Space),
-    });
+        jump,
+    }
 }
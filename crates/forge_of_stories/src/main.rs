@@ -1,9 +1,15 @@
 mod app;
+mod chat;
+mod console;
 mod input;
+mod protocol;
 mod ui;
 mod utils;
 
+use crate::chat::ChatPlugin;
+use crate::console::ConsolePlugin;
 use crate::input::InputPlugin;
+use crate::protocol::ProtocolPlugin;
 use crate::ui::UIPlugin;
 
 use bevy::prelude::*;
@@ -28,6 +34,9 @@ fn main() {
     // Add EnhancedInputPlugin BEFORE KeymapInputPlugin
     app.add_plugins((
         EnhancedInputPlugin, // TODO: in port in keymap plugin
+        ProtocolPlugin,
+        ChatPlugin,
+        ConsolePlugin,
         InputPlugin,
         UIPlugin,
     ));
@@ -0,0 +1,35 @@
+//! Network protocol.
+//!
+//! bevy_replicon identifies network events and replicated components by the order they are
+//! registered in, so client and server must register the same list in the same order. All
+//! registrations of the client live here instead of in the plugins that use them; the server
+//! registers this exact list next to its own `PlayerMovement`.
+
+use crate::chat::{ChatBroadcast, ChatMessage};
+use crate::console::AdminTeleport;
+use crate::input::click_to_move::PlayerMoveTarget;
+use crate::input::local_players::{JoinLocalPlayer, LeaveLocalPlayer, LocalPlayerMovement};
+use crate::ui::scenes::in_game::player_list::{ClientPing, PlayerRole};
+use crate::ui::scenes::in_game::world::DisplayName;
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use game_server::PlayerMovement;
+
+/// Registers every network event and replicated component, in protocol order.
+pub struct ProtocolPlugin;
+
+impl Plugin for ProtocolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_client_event::<PlayerMovement>(Channel::Unreliable)
+            .add_client_event::<ChatMessage>(Channel::Ordered)
+            .add_server_event::<ChatBroadcast>(Channel::Ordered)
+            .add_client_event::<AdminTeleport>(Channel::Ordered)
+            .add_client_event::<PlayerMoveTarget>(Channel::Ordered)
+            .add_client_event::<JoinLocalPlayer>(Channel::Ordered)
+            .add_client_event::<LeaveLocalPlayer>(Channel::Ordered)
+            .add_client_event::<LocalPlayerMovement>(Channel::Unreliable)
+            .replicate::<DisplayName>()
+            .replicate::<ClientPing>()
+            .replicate::<PlayerRole>();
+    }
+}
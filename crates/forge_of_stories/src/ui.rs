@@ -1,11 +1,19 @@
 pub mod components;
+pub mod console;
+pub mod debug_overlay;
+pub mod icons;
 pub mod normal_vector;
+pub mod notifications;
 pub mod scenes;
 
 use bevy::{input_focus::InputFocus, prelude::*};
 
-use components::InGameMenuState;
+use components::{InGameMenuState, PhotoModeState};
+use console::DevConsolePlugin;
+use debug_overlay::{DebugOverlayPlugin, debug_gizmos_enabled};
+use icons::IconsPlugin;
 use normal_vector::draw_local_coordinate_systems;
+use notifications::NotificationsPlugin;
 use scenes::ScenePlugin;
 
 /// Main UI plugin that coordinates cameras, scenes, and UI systems
@@ -13,10 +21,20 @@ pub struct UIPlugin;
 
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ScenePlugin)
-            .init_resource::<InGameMenuState>()
-            .init_resource::<InputFocus>()
-            // Debug helper for normal vectors
-            .add_systems(Update, draw_local_coordinate_systems);
+        app.add_plugins((
+            IconsPlugin,
+            DebugOverlayPlugin,
+            DevConsolePlugin,
+            NotificationsPlugin,
+            ScenePlugin,
+        ))
+        .init_resource::<InGameMenuState>()
+        .init_resource::<PhotoModeState>()
+        .init_resource::<InputFocus>()
+        // Debug helper for normal vectors (toggled via the debug overlay)
+        .add_systems(
+            Update,
+            draw_local_coordinate_systems.run_if(debug_gizmos_enabled),
+        );
     }
 }
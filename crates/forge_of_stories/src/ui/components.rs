@@ -38,3 +38,137 @@ impl InGameMenuState {
 pub fn menu_allows_input(menu: Res<InGameMenuState>) -> bool {
     menu.is_closed()
 }
+
+/// Resource tracking the chat input line (opened with Enter)
+#[derive(Resource, Default)]
+pub struct ChatInputState {
+    open: bool,
+    /// Text typed so far
+    pub buffer: String,
+}
+
+impl ChatInputState {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.buffer.clear();
+    }
+
+    /// Closes the input line and returns the typed text.
+    pub fn close(&mut self) -> String {
+        self.open = false;
+        std::mem::take(&mut self.buffer)
+    }
+}
+
+/// System condition: Returns true if the chat input line is closed.
+/// Use with `.run_if(chat_allows_input)` to disable gameplay input while typing.
+pub fn chat_allows_input(chat: Res<ChatInputState>) -> bool {
+    !chat.is_open()
+}
+
+/// Resource tracking the full-screen map view (opened with M)
+#[derive(Resource, Default)]
+pub struct MapViewState {
+    open: bool,
+}
+
+impl MapViewState {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn set_closed(&mut self) {
+        self.open = false;
+    }
+}
+
+/// System condition: Returns true if the full-screen map is closed.
+pub fn map_allows_input(map: Res<MapViewState>) -> bool {
+    !map.is_open()
+}
+
+/// Resource tracking photo mode (entered from the in-game menu)
+#[derive(Resource, Default)]
+pub struct PhotoModeState {
+    active: bool,
+}
+
+impl PhotoModeState {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self) {
+        self.active = true;
+    }
+
+    pub fn set_inactive(&mut self) {
+        self.active = false;
+    }
+}
+
+/// System condition: Returns true outside photo mode.
+/// Gameplay and camera input is off while the photo camera is flown.
+pub fn photo_mode_allows_input(photo: Res<PhotoModeState>) -> bool {
+    !photo.is_active()
+}
+
+/// Resource tracking the developer console (toggled with `)
+#[derive(Resource, Default)]
+pub struct ConsoleState {
+    open: bool,
+    /// Text typed so far
+    pub buffer: String,
+    /// Position while browsing the history with Up/Down (`None` = editing a new line)
+    pub history_index: Option<usize>,
+    /// Candidates of the last Tab completion, shown below the input line
+    pub completions: Vec<String>,
+}
+
+impl ConsoleState {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.history_index = None;
+        self.completions.clear();
+    }
+
+    /// Clears the input line and returns the typed text.
+    pub fn take_buffer(&mut self) -> String {
+        self.history_index = None;
+        self.completions.clear();
+        std::mem::take(&mut self.buffer)
+    }
+}
+
+/// System condition: Returns true if the developer console is closed.
+pub fn console_allows_input(console: Res<ConsoleState>) -> bool {
+    !console.is_open()
+}
+
+/// System condition: Returns true while gameplay and camera input reach the game, i.e. no chat
+/// line or console is typed into, the full-screen map is closed and photo mode is off.
+/// Use with `.run_if(gameplay_input_allowed)` instead of chaining the individual conditions.
+pub fn gameplay_input_allowed(
+    chat: Res<ChatInputState>,
+    console: Res<ConsoleState>,
+    map: Res<MapViewState>,
+    photo: Res<PhotoModeState>,
+) -> bool {
+    !chat.is_open() && !console.is_open() && !map.is_open() && !photo.is_active()
+}
@@ -0,0 +1,199 @@
+//! Drop-down developer console UI
+//!
+//! Renders [`ConsoleOutput`] and the input line of the developer console at the top of the
+//! screen. The console works in every game state.
+
+use crate::console::{ConsoleLineKind, ConsoleOutput};
+use crate::ui::components::ConsoleState;
+use crate::ui::icons::Icons;
+use bevy::prelude::*;
+
+/// Number of output lines rendered.
+const VISIBLE_LINES: usize = 24;
+const FONT_SIZE: f32 = 14.0;
+
+const INPUT_COLOR: Color = Color::srgb(0.6, 0.85, 1.0);
+const OUTPUT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const ERROR_COLOR: Color = Color::srgb(1.0, 0.45, 0.45);
+const WARN_COLOR: Color = Color::srgb(0.95, 0.8, 0.35);
+const LOG_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+
+/// Plugin for the developer console UI
+pub struct DevConsolePlugin;
+
+impl Plugin for DevConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_console_ui).add_systems(
+            Update,
+            (
+                update_console_visibility,
+                rebuild_console_output,
+                update_console_input,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Root of the console panel
+#[derive(Component)]
+struct ConsolePanel;
+
+/// Container for the output lines
+#[derive(Component)]
+struct ConsoleOutputList;
+
+/// The input line
+#[derive(Component)]
+struct ConsoleInputLine;
+
+/// Completion candidates below the input line
+#[derive(Component)]
+struct ConsoleCompletions;
+
+fn spawn_console_ui(mut commands: Commands, icons: Res<Icons>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(40.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::FlexEnd,
+                overflow: Overflow::clip(),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.92)),
+            GlobalZIndex(100),
+            ConsolePanel,
+            Name::new("Developer Console"),
+        ))
+        .with_children(|panel| {
+            panel
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    margin: UiRect::bottom(Val::Px(4.0)),
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn(icons.node("square-terminal", FONT_SIZE + 4.0, LOG_COLOR));
+                    header.spawn((
+                        Text::new("Console"),
+                        TextFont {
+                            font_size: FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(LOG_COLOR),
+                    ));
+                });
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    flex_grow: 1.0,
+                    justify_content: JustifyContent::FlexEnd,
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                ConsoleOutputList,
+            ));
+            panel.spawn((
+                Text::new("> _"),
+                TextFont {
+                    font_size: FONT_SIZE + 2.0,
+                    ..default()
+                },
+                TextColor(INPUT_COLOR),
+                Node {
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                },
+                ConsoleInputLine,
+            ));
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: FONT_SIZE,
+                    ..default()
+                },
+                TextColor(LOG_COLOR),
+                ConsoleCompletions,
+            ));
+        });
+}
+
+fn update_console_visibility(
+    console: Res<ConsoleState>,
+    mut panel: Query<&mut Node, With<ConsolePanel>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    for mut node in &mut panel {
+        node.display = if console.is_open() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+fn rebuild_console_output(
+    mut commands: Commands,
+    console: Res<ConsoleState>,
+    output: Res<ConsoleOutput>,
+    list: Query<Entity, With<ConsoleOutputList>>,
+) {
+    // Only rebuild while visible, and once more when the console is opened
+    if !console.is_open() || !(output.is_changed() || console.is_changed()) {
+        return;
+    }
+
+    let Ok(list) = list.single() else {
+        return;
+    };
+
+    commands.entity(list).despawn_children();
+    let lines: Vec<_> = output.lines().rev().take(VISIBLE_LINES).cloned().collect();
+    commands.entity(list).with_children(|list| {
+        for line in lines.into_iter().rev() {
+            let color = match line.kind {
+                ConsoleLineKind::Input => INPUT_COLOR,
+                ConsoleLineKind::Output => OUTPUT_COLOR,
+                ConsoleLineKind::Error | ConsoleLineKind::Log(tracing::Level::ERROR) => ERROR_COLOR,
+                ConsoleLineKind::Log(tracing::Level::WARN) => WARN_COLOR,
+                ConsoleLineKind::Log(_) => LOG_COLOR,
+            };
+            list.spawn((
+                Text::new(line.text),
+                TextFont {
+                    font_size: FONT_SIZE,
+                    ..default()
+                },
+                TextColor(color),
+            ));
+        }
+    });
+}
+
+fn update_console_input(
+    console: Res<ConsoleState>,
+    mut input_line: Query<&mut Text, (With<ConsoleInputLine>, Without<ConsoleCompletions>)>,
+    mut completions: Query<&mut Text, (With<ConsoleCompletions>, Without<ConsoleInputLine>)>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    if let Ok(mut text) = input_line.single_mut() {
+        text.0 = format!("> {}_", console.buffer);
+    }
+    if let Ok(mut text) = completions.single_mut() {
+        text.0 = console.completions.join("  ");
+    }
+}
@@ -0,0 +1,407 @@
+//! F3 debug overlay for gameplay diagnostics.
+//!
+//! Shows FPS with a frame-time graph, the current `GameState` and cursor state, the transform,
+//! camera mode and camera parameters of every local player and entity counts per scene marker.
+//! Scenes register their marker components via [`DebugOverlayAppExt::register_debug_marker`].
+//!
+//! - **F3** - toggle the overlay
+//! - **Shift+F3** - toggle the local coordinate system gizmos
+
+use crate::GameState;
+use crate::networking::LocalPlayer;
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CameraMode, CameraTransition, CursorState, FirstPersonView, FreeFlyCamera,
+    InGameCamera, LocalPlayerSlot, PanOrbitCamera, PlayerInputDevice, ThirdPersonCamera,
+};
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+/// Number of bars in the frame-time graph (one per recorded frame).
+const GRAPH_BARS: usize = 120;
+/// Frame time (ms) that fills the full graph height.
+const GRAPH_MAX_MS: f64 = 50.0;
+const GRAPH_HEIGHT: f32 = 60.0;
+
+/// Plugin for the debug overlay
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+
+        app.init_resource::<DebugOverlayState>()
+            .init_resource::<DebugEntityCounts>()
+            .add_systems(
+                Update,
+                (
+                    handle_debug_overlay_toggle,
+                    spawn_debug_overlay_ui,
+                    (update_debug_text, update_frame_time_graph)
+                        .after(DebugOverlaySystems::CountMarkers)
+                        .run_if(debug_overlay_visible),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// System sets used by the debug overlay
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DebugOverlaySystems {
+    /// Systems counting registered scene markers
+    CountMarkers,
+}
+
+/// Resource tracking debug overlay visibility and toggles
+#[derive(Resource, Debug)]
+pub struct DebugOverlayState {
+    pub visible: bool,
+    pub gizmos: bool,
+}
+
+impl Default for DebugOverlayState {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            gizmos: true,
+        }
+    }
+}
+
+/// Entity counts per registered scene marker, in registration order
+#[derive(Resource, Default, Debug)]
+pub struct DebugEntityCounts(Vec<(&'static str, usize)>);
+
+/// Extension trait for registering marker components whose entity count is shown in the overlay.
+pub trait DebugOverlayAppExt {
+    fn register_debug_marker<C: Component>(&mut self, label: &'static str) -> &mut Self;
+}
+
+impl DebugOverlayAppExt for App {
+    fn register_debug_marker<C: Component>(&mut self, label: &'static str) -> &mut Self {
+        self.init_resource::<DebugOverlayState>()
+            .init_resource::<DebugEntityCounts>();
+
+        let index = {
+            let mut counts = self.world_mut().resource_mut::<DebugEntityCounts>();
+            counts.0.push((label, 0));
+            counts.0.len() - 1
+        };
+
+        self.add_systems(
+            Update,
+            (move |markers: Query<(), With<C>>, mut counts: ResMut<DebugEntityCounts>| {
+                counts.0[index].1 = markers.iter().count();
+            })
+            .in_set(DebugOverlaySystems::CountMarkers)
+            .run_if(debug_overlay_visible),
+        )
+    }
+}
+
+/// Run condition: Returns true if the debug overlay is shown.
+pub fn debug_overlay_visible(state: Res<DebugOverlayState>) -> bool {
+    state.visible
+}
+
+/// Run condition: Returns true if debug gizmos should be drawn.
+pub fn debug_gizmos_enabled(state: Res<DebugOverlayState>) -> bool {
+    state.gizmos
+}
+
+/// Marker component for debug overlay UI entities
+#[derive(Component)]
+struct DebugOverlayUI;
+
+/// Marker for the overlay's diagnostic text
+#[derive(Component)]
+struct DebugOverlayText;
+
+/// A single bar of the frame-time graph; the index counts from the oldest sample.
+#[derive(Component)]
+struct FrameTimeBar(usize);
+
+/// Handles F3 (overlay) and Shift+F3 (gizmos).
+fn handle_debug_overlay_toggle(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<DebugOverlayState>,
+) {
+    if !keyboard.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        state.gizmos = !state.gizmos;
+    } else {
+        state.visible = !state.visible;
+    }
+}
+
+fn spawn_debug_overlay_ui(
+    mut commands: Commands,
+    state: Res<DebugOverlayState>,
+    existing: Query<Entity, With<DebugOverlayUI>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    // Despawn existing overlay if hidden
+    if !state.visible {
+        for entity in &existing {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    // Don't spawn if already exists
+    if !existing.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(6.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            GlobalZIndex(100),
+            DebugOverlayUI,
+            Name::new("Debug Overlay"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                DebugOverlayText,
+            ));
+
+            // Frame-time graph
+            parent
+                .spawn((
+                    Node {
+                        height: Val::Px(GRAPH_HEIGHT),
+                        align_items: AlignItems::FlexEnd,
+                        column_gap: Val::Px(1.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+                ))
+                .with_children(|graph| {
+                    for index in 0..GRAPH_BARS {
+                        graph.spawn((
+                            Node {
+                                width: Val::Px(2.0),
+                                height: Val::Px(0.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.3, 0.8, 0.3)),
+                            FrameTimeBar(index),
+                        ));
+                    }
+                });
+        });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_debug_text(
+    diagnostics: Res<DiagnosticsStore>,
+    game_state: Res<State<GameState>>,
+    cursor: Res<CursorState>,
+    overlay: Res<DebugOverlayState>,
+    counts: Res<DebugEntityCounts>,
+    local_players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    cameras: Query<
+        (
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
+            &ActiveCameraMode,
+            &Transform,
+            &FirstPersonView,
+            &PanOrbitCamera,
+            &ThirdPersonCamera,
+            &FreeFlyCamera,
+            Option<&CameraTransition>,
+        ),
+        (With<InGameCamera>, Without<LocalPlayer>),
+    >,
+    mut text: Query<&mut Text, With<DebugOverlayText>>,
+) {
+    let Ok(mut text) = text.single_mut() else {
+        return;
+    };
+
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+        .unwrap_or_default();
+    let frame_time = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        format!("FPS: {fps:.0} ({frame_time:.2} ms)"),
+        format!("GameState: {:?}", game_state.get()),
+        format!("Cursor: {}", cursor_label(&cursor)),
+    ];
+
+    // One section per local player, in slot order
+    let mut cameras: Vec<_> = cameras.iter().collect();
+    cameras.sort_by_key(|(slot, ..)| slot.0);
+    for (
+        slot,
+        device,
+        active_mode,
+        transform,
+        fp_view,
+        pan_orbit,
+        third_person,
+        free_fly,
+        transition,
+    ) in cameras
+    {
+        lines.push(format!("Player {} ({})", slot.0 + 1, device.label()));
+
+        if let Some(player) = slot.find(local_players.iter()) {
+            let (yaw, pitch, roll) = player.rotation.to_euler(EulerRot::YXZ);
+            lines.push(format!(
+                "  Player pos: ({:.2}, {:.2}, {:.2})",
+                player.translation.x, player.translation.y, player.translation.z
+            ));
+            lines.push(format!(
+                "  Player rot: yaw {:.1}° pitch {:.1}° roll {:.1}°",
+                yaw.to_degrees(),
+                pitch.to_degrees(),
+                roll.to_degrees()
+            ));
+        }
+
+        match transition {
+            Some(transition) => lines.push(format!(
+                "  Camera mode: {:?} (blending {:.0}%)",
+                active_mode.mode,
+                transition.progress() * 100.0
+            )),
+            None => lines.push(format!("  Camera mode: {:?}", active_mode.mode)),
+        }
+        lines.push(format!(
+            "  Camera pos: ({:.2}, {:.2}, {:.2})",
+            transform.translation.x, transform.translation.y, transform.translation.z
+        ));
+        match active_mode.mode {
+            CameraMode::FirstPerson => {
+                lines.push(format!(
+                    "  View: yaw {:.1}° pitch {:.1}° hfov {:.0}° (aim {:.0}%)",
+                    fp_view.yaw.to_degrees(),
+                    fp_view.pitch.to_degrees(),
+                    fp_view.current_horizontal_fov().to_degrees(),
+                    fp_view.aim * 100.0
+                ));
+            }
+            CameraMode::ThirdPerson => {
+                lines.push(format!(
+                    "  Arm: yaw {:.1}° pitch {:.1}° length {:.2}/{:.2}",
+                    third_person.yaw.to_degrees(),
+                    third_person.pitch.to_degrees(),
+                    third_person.current_distance,
+                    third_person.distance
+                ));
+            }
+            CameraMode::PanOrbit | CameraMode::Isometric => {
+                lines.push(format!(
+                    "  Orbit: yaw {:.1}° pitch {:.1}° radius {:.2}",
+                    pan_orbit.yaw.unwrap_or_default().to_degrees(),
+                    pan_orbit.pitch.unwrap_or_default().to_degrees(),
+                    pan_orbit.radius.unwrap_or_default()
+                ));
+                lines.push(format!(
+                    "  Focus: ({:.2}, {:.2}, {:.2})",
+                    pan_orbit.focus.x, pan_orbit.focus.y, pan_orbit.focus.z
+                ));
+            }
+            CameraMode::FreeFly => {
+                lines.push(format!(
+                    "  Free fly: yaw {:.1}° pitch {:.1}° speed {:.1} m/s",
+                    free_fly.yaw.to_degrees(),
+                    free_fly.pitch.to_degrees(),
+                    free_fly.speed
+                ));
+            }
+        }
+    }
+
+    lines.push("Entities:".to_string());
+    for (label, count) in &counts.0 {
+        lines.push(format!("  {label}: {count}"));
+    }
+
+    lines.push(format!(
+        "Gizmos: {} (Shift+F3)",
+        if overlay.gizmos { "on" } else { "off" }
+    ));
+
+    text.0 = lines.join("\n");
+}
+
+fn update_frame_time_graph(
+    diagnostics: Res<DiagnosticsStore>,
+    mut bars: Query<(&FrameTimeBar, &mut Node, &mut BackgroundColor)>,
+) {
+    let Some(frame_time) = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME) else {
+        return;
+    };
+
+    // Right-align the history so the newest sample is always the last bar
+    let samples: Vec<f64> = frame_time.values().copied().collect();
+    let offset = GRAPH_BARS.saturating_sub(samples.len());
+
+    for (bar, mut node, mut color) in &mut bars {
+        let ms = bar
+            .0
+            .checked_sub(offset)
+            .and_then(|index| samples.get(index))
+            .copied()
+            .unwrap_or_default();
+
+        let height = (ms / GRAPH_MAX_MS).min(1.0) as f32 * GRAPH_HEIGHT;
+        node.height = Val::Px(height);
+        color.0 = frame_time_color(ms);
+    }
+}
+
+/// Green below 60 FPS budget, yellow below 30 FPS budget, red above.
+fn frame_time_color(ms: f64) -> Color {
+    if ms <= 1000.0 / 60.0 {
+        Color::srgb(0.3, 0.8, 0.3)
+    } else if ms <= 1000.0 / 30.0 {
+        Color::srgb(0.9, 0.8, 0.2)
+    } else {
+        Color::srgb(0.9, 0.3, 0.3)
+    }
+}
+
+fn cursor_label(cursor: &CursorState) -> &'static str {
+    if *cursor == CursorState::LOCKED {
+        "Locked"
+    } else if *cursor == CursorState::CONFINED {
+        "Confined"
+    } else if *cursor == CursorState::FREE {
+        "Free"
+    } else {
+        "Custom"
+    }
+}
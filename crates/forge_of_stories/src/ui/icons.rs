@@ -0,0 +1,117 @@
+//! SVG icons
+//!
+//! The icon set under `assets/fate/icons` is embedded into the binary and rasterised once at
+//! startup, since Bevy can't load SVG files itself. Icons are rendered white so an
+//! [`ImageNode`] can tint them with its colour.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use resvg::{tiny_skia, usvg};
+use rust_embed::RustEmbed;
+use std::collections::HashMap;
+
+/// Edge length of the rasterised icons in pixels, twice the 24 px of the icon set.
+const ICON_RESOLUTION: u32 = 48;
+
+#[derive(RustEmbed)]
+#[folder = "../../assets/fate/icons/"]
+#[include = "*.svg"]
+struct IconAssets;
+
+/// Plugin rasterising the embedded icons into [`Icons`]
+pub struct IconsPlugin;
+
+impl Plugin for IconsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Icons>();
+    }
+}
+
+/// Rasterised icons by file name without extension, e.g. `"bell"` for `bell.svg`.
+#[derive(Resource)]
+pub struct Icons {
+    images: HashMap<String, Handle<Image>>,
+}
+
+impl Icons {
+    /// Image of the icon `name`; an empty image if there is no such icon.
+    pub fn get(&self, name: &str) -> Handle<Image> {
+        self.images.get(name).cloned().unwrap_or_else(|| {
+            warn!("unknown icon '{name}'");
+            Handle::default()
+        })
+    }
+
+    /// UI node showing the icon `name` in `color`, `size` logical pixels wide and high.
+    pub fn node(&self, name: &str, size: f32, color: Color) -> impl Bundle + use<> {
+        (
+            ImageNode::new(self.get(name)).with_color(color),
+            Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..default()
+            },
+        )
+    }
+}
+
+impl FromWorld for Icons {
+    fn from_world(world: &mut World) -> Self {
+        let mut assets = world.resource_mut::<Assets<Image>>();
+        let mut images = HashMap::new();
+        for file in IconAssets::iter() {
+            let Some(name) = file.strip_suffix(".svg") else {
+                continue;
+            };
+            let Some(svg) = IconAssets::get(&file) else {
+                continue;
+            };
+            match rasterize(&svg.data) {
+                Ok(image) => {
+                    images.insert(name.to_string(), assets.add(image));
+                }
+                Err(err) => warn!("could not rasterise icon '{file}': {err}"),
+            }
+        }
+        Self { images }
+    }
+}
+
+/// Renders an SVG into a square image of [`ICON_RESOLUTION`] pixels.
+fn rasterize(svg: &[u8]) -> Result<Image, usvg::Error> {
+    // The icons stroke with `currentColor`, which would render black
+    let svg = String::from_utf8_lossy(svg).replace("currentColor", "white");
+    let tree = usvg::Tree::from_str(&svg, &usvg::Options::default())?;
+
+    let size = tree.size();
+    let scale = ICON_RESOLUTION as f32 / size.width().max(size.height());
+    let mut pixmap = tiny_skia::Pixmap::new(ICON_RESOLUTION, ICON_RESOLUTION)
+        .expect("icon resolution is not zero");
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    // tiny-skia stores premultiplied alpha
+    let data = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    Ok(Image::new(
+        Extent3d {
+            width: ICON_RESOLUTION,
+            height: ICON_RESOLUTION,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    ))
+}
@@ -0,0 +1,670 @@
+//! Toast notifications and notification history.
+//!
+//! Any system can show a toast by triggering a [`Notify`] event:
+//!
+//! ```ignore
+//! commands.trigger(Notify::success("Server ready"));
+//! ```
+//!
+//! Toasts stack in the top centre of the screen and disappear after their duration. Every
+//! notification is also kept in [`NotificationHistory`], which is shown in a panel toggled with
+//! **N** or the bell button. Tracing events can be routed into toasts by target and level via
+//! [`LogToastRoutes`].
+
+use crate::app::{LOG_CLIENT, LOG_MAIN};
+use crate::ui::components::{ChatInputState, ConsoleState, HOVERED_BUTTON, NORMAL_BUTTON};
+use crate::ui::icons::Icons;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tracing::Level;
+use tracing::field::{Field, Visit};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+
+/// Maximum number of toasts shown at once; older toasts are dropped first.
+const MAX_VISIBLE_TOASTS: usize = 5;
+/// Number of notifications kept in the history.
+const HISTORY_LEN: usize = 100;
+/// Time a toast takes to fade out at the end of its duration.
+const TOAST_FADE_SECS: f32 = 0.4;
+const DEFAULT_DURATION: Duration = Duration::from_secs(4);
+
+/// Plugin for toast notifications
+pub struct NotificationsPlugin;
+
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NotificationHistory>()
+            .init_resource::<NotificationPanelState>()
+            .init_resource::<LogToastRoutes>()
+            .add_observer(show_toast)
+            .add_systems(Startup, spawn_notification_ui)
+            .add_systems(
+                Update,
+                (
+                    forward_log_toasts,
+                    expire_toasts,
+                    handle_toast_actions,
+                    handle_panel_toggle,
+                    update_history_panel,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Severity of a notification, used for colouring and default icons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn color(self) -> Color {
+        match self {
+            Severity::Info => Color::srgb(0.35, 0.6, 0.95),
+            Severity::Success => Color::srgb(0.35, 0.75, 0.35),
+            Severity::Warning => Color::srgb(0.95, 0.75, 0.25),
+            Severity::Error => Color::srgb(0.9, 0.3, 0.3),
+        }
+    }
+
+    fn default_icon(self) -> NotifyIcon {
+        match self {
+            Severity::Info => NotifyIcon::Info,
+            Severity::Success => NotifyIcon::Check,
+            Severity::Warning => NotifyIcon::Alert,
+            Severity::Error => NotifyIcon::Error,
+        }
+    }
+}
+
+impl From<Level> for Severity {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => Severity::Error,
+            Level::WARN => Severity::Warning,
+            _ => Severity::Info,
+        }
+    }
+}
+
+/// Icon shown in front of a toast
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyIcon {
+    Info,
+    Check,
+    Alert,
+    Error,
+    User,
+    Server,
+    Settings,
+}
+
+impl NotifyIcon {
+    /// Name of the icon in [`Icons`]
+    fn icon_name(self) -> &'static str {
+        match self {
+            NotifyIcon::Info => "info",
+            NotifyIcon::Check => "circle-check",
+            NotifyIcon::Alert => "triangle-alert",
+            NotifyIcon::Error => "circle-x",
+            NotifyIcon::User => "user",
+            NotifyIcon::Server => "globe",
+            NotifyIcon::Settings => "settings",
+        }
+    }
+}
+
+/// Optional button on a toast that runs a registered one-shot system when clicked.
+#[derive(Debug, Clone)]
+pub struct NotifyAction {
+    pub label: String,
+    pub system: SystemId,
+}
+
+/// Event that shows a toast notification.
+#[derive(Event, Debug, Clone)]
+pub struct Notify {
+    pub severity: Severity,
+    pub message: String,
+    pub icon: NotifyIcon,
+    pub duration: Duration,
+    pub action: Option<NotifyAction>,
+}
+
+impl Notify {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            icon: severity.default_icon(),
+            duration: DEFAULT_DURATION,
+            action: None,
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(Severity::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(Severity::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn with_icon(mut self, icon: NotifyIcon) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_action(mut self, label: impl Into<String>, system: SystemId) -> Self {
+        self.action = Some(NotifyAction {
+            label: label.into(),
+            system,
+        });
+        self
+    }
+}
+
+/// A past notification
+#[derive(Debug, Clone)]
+pub struct NotificationEntry {
+    pub severity: Severity,
+    pub icon: NotifyIcon,
+    pub message: String,
+    pub timestamp: chrono::DateTime<chrono::Local>,
+}
+
+/// Resource holding all recent notifications, newest last
+#[derive(Resource, Default, Debug)]
+pub struct NotificationHistory {
+    entries: VecDeque<NotificationEntry>,
+}
+
+impl NotificationHistory {
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &NotificationEntry> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Resource tracking whether the notification history panel is open
+#[derive(Resource, Default)]
+pub struct NotificationPanelState {
+    pub open: bool,
+}
+
+/// Rule that turns tracing events into toasts.
+#[derive(Debug, Clone)]
+pub struct LogToastRoute {
+    /// Target prefix to match, e.g. `LOG_CLIENT_HOST`. `None` matches every target.
+    pub target: Option<String>,
+    /// Least severe level that is routed (e.g. `Level::WARN` routes warnings and errors).
+    pub min_level: Level,
+}
+
+/// Routing rules for tracing events → toasts. Shared with the tracing layer, so changes
+/// apply immediately.
+#[derive(Resource, Clone)]
+pub struct LogToastRoutes(Arc<RwLock<Vec<LogToastRoute>>>);
+
+impl Default for LogToastRoutes {
+    fn default() -> Self {
+        // Surface warnings and errors of the game itself; engine and driver crates (wgpu,
+        // bevy_render, …) log too much to toast by default
+        Self(Arc::new(RwLock::new(
+            [LOG_MAIN, LOG_CLIENT]
+                .into_iter()
+                .map(|target| LogToastRoute {
+                    target: Some(target.to_string()),
+                    min_level: Level::WARN,
+                })
+                .collect(),
+        )))
+    }
+}
+
+impl LogToastRoutes {
+    /// Routes events from `target` (prefix match) at `min_level` or more severe into toasts.
+    pub fn route(&self, target: impl Into<String>, min_level: Level) {
+        if let Ok(mut routes) = self.0.write() {
+            routes.push(LogToastRoute {
+                target: Some(target.into()),
+                min_level,
+            });
+        }
+    }
+
+    /// Removes all rules for `target`.
+    pub fn unroute(&self, target: &str) {
+        if let Ok(mut routes) = self.0.write() {
+            routes.retain(|route| route.target.as_deref() != Some(target));
+        }
+    }
+
+    fn matches(&self, target: &str, level: &Level) -> bool {
+        let Ok(routes) = self.0.read() else {
+            return false;
+        };
+        routes.iter().any(|route| {
+            level <= &route.min_level
+                && route
+                    .target
+                    .as_deref()
+                    .is_none_or(|prefix| target.starts_with(prefix))
+        })
+    }
+}
+
+/// Receives toasts produced by the tracing layer
+#[derive(Resource)]
+struct LogToastReceiver(Mutex<Receiver<Notify>>);
+
+/// Tracing layer that forwards routed events to [`LogToastReceiver`].
+pub struct ToastLayer {
+    routes: LogToastRoutes,
+    sender: Sender<Notify>,
+}
+
+impl<S: tracing::Subscriber> Layer<S> for ToastLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if !self.routes.matches(metadata.target(), metadata.level()) {
+            return;
+        }
+
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let _ = self
+            .sender
+            .send(Notify::new((*metadata.level()).into(), visitor.0));
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+/// Creates the tracing layer for log → toast routing. Call while setting up logging.
+pub fn toast_layer(world: &mut World) -> ToastLayer {
+    let routes = world.get_resource_or_init::<LogToastRoutes>().clone();
+    let (sender, receiver) = channel();
+    world.insert_resource(LogToastReceiver(Mutex::new(receiver)));
+    ToastLayer { routes, sender }
+}
+
+/// Root nodes of the notification UI (toast stack, bell button and history panel)
+#[derive(Component)]
+pub struct NotificationLayer;
+
+/// Container the toasts are stacked in
+#[derive(Component)]
+struct ToastStack;
+
+/// A visible toast
+#[derive(Component)]
+struct Toast {
+    timer: Timer,
+    color: Color,
+}
+
+/// Button on a toast running its action
+#[derive(Component)]
+struct ToastActionButton(SystemId);
+
+/// Bell button toggling the history panel
+#[derive(Component)]
+struct BellButton;
+
+/// The notification history panel
+#[derive(Component)]
+struct HistoryPanel;
+
+/// List of entries inside the history panel
+#[derive(Component)]
+struct HistoryList;
+
+fn spawn_notification_ui(mut commands: Commands, icons: Res<Icons>) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        GlobalZIndex(50),
+        ToastStack,
+        NotificationLayer,
+        Name::new("Toast Stack"),
+    ));
+
+    commands
+        .spawn((
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(NORMAL_BUTTON),
+            GlobalZIndex(40),
+            BellButton,
+            NotificationLayer,
+            Name::new("Notification Bell (N)"),
+        ))
+        .with_children(|button| {
+            button.spawn(icons.node("bell", 20.0, Color::srgb(0.9, 0.9, 0.9)));
+        });
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(44.0),
+                right: Val::Px(10.0),
+                width: Val::Px(360.0),
+                max_height: Val::Percent(70.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                overflow: Overflow::scroll_y(),
+                display: Display::None,
+                ..default()
+            },
+            BorderRadius::all(Val::Px(8.0)),
+            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.95)),
+            GlobalZIndex(40),
+            HistoryPanel,
+            NotificationLayer,
+            Name::new("Notification History"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("Notifications"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+            panel.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                HistoryList,
+            ));
+        });
+}
+
+/// Observer: records the notification and spawns a toast for it.
+fn show_toast(
+    notify: On<Notify>,
+    mut commands: Commands,
+    mut history: ResMut<NotificationHistory>,
+    icons: Res<Icons>,
+    stack: Query<Entity, With<ToastStack>>,
+    toasts: Query<Entity, With<Toast>>,
+) {
+    if history.entries.len() >= HISTORY_LEN {
+        history.entries.pop_front();
+    }
+    history.entries.push_back(NotificationEntry {
+        severity: notify.severity,
+        icon: notify.icon,
+        message: notify.message.clone(),
+        timestamp: chrono::Local::now(),
+    });
+
+    let Ok(stack) = stack.single() else {
+        return;
+    };
+
+    // Drop the oldest toasts if too many are visible
+    let visible: Vec<Entity> = toasts.iter().collect();
+    for entity in visible
+        .iter()
+        .take((visible.len() + 1).saturating_sub(MAX_VISIBLE_TOASTS))
+    {
+        commands.entity(*entity).despawn();
+    }
+
+    let color = notify.severity.color();
+    let toast = commands
+        .spawn((
+            Node {
+                min_width: Val::Px(280.0),
+                max_width: Val::Px(480.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                column_gap: Val::Px(10.0),
+                border: UiRect::left(Val::Px(4.0)),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor::all(color),
+            BorderRadius::all(Val::Px(6.0)),
+            BackgroundColor(Color::srgba(0.08, 0.08, 0.08, 0.92)),
+            Toast {
+                timer: Timer::new(notify.duration, TimerMode::Once),
+                color,
+            },
+            Name::new("Toast"),
+        ))
+        .with_children(|toast| {
+            toast.spawn(icons.node(notify.icon.icon_name(), 20.0, color));
+            toast.spawn((
+                Text::new(notify.message.clone()),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+            ));
+
+            if let Some(action) = &notify.action {
+                toast
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::axes(Val::Px(8.0), Val::Px(2.0)),
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(4.0)),
+                        BackgroundColor(NORMAL_BUTTON),
+                        ToastActionButton(action.system),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(action.label.clone()),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(color),
+                        ));
+                    });
+            }
+        })
+        .id();
+
+    commands.entity(stack).add_child(toast);
+}
+
+fn forward_log_toasts(mut commands: Commands, receiver: Option<Res<LogToastReceiver>>) {
+    let Some(receiver) = receiver else {
+        return;
+    };
+    let Ok(receiver) = receiver.0.lock() else {
+        return;
+    };
+    for notify in receiver.try_iter() {
+        commands.trigger(notify);
+    }
+}
+
+/// Ticks toast timers, fades them out at the end and despawns expired toasts.
+fn expire_toasts(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut toasts: Query<(Entity, &mut Toast, &mut BackgroundColor, &mut BorderColor)>,
+) {
+    for (entity, mut toast, mut background, mut border) in &mut toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let remaining = toast.timer.remaining_secs();
+        let alpha = (remaining / TOAST_FADE_SECS).clamp(0.0, 1.0);
+        background.0 = background.0.with_alpha(0.92 * alpha);
+        *border = BorderColor::all(toast.color.with_alpha(alpha));
+    }
+}
+
+fn handle_toast_actions(
+    mut commands: Commands,
+    mut buttons: Query<
+        (&Interaction, &ToastActionButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+) {
+    for (interaction, action, mut color) in &mut buttons {
+        match *interaction {
+            Interaction::Pressed => commands.run_system(action.0),
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+/// Handles N and the bell button to toggle the history panel.
+fn handle_panel_toggle(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    chat: Option<Res<ChatInputState>>,
+    console: Option<Res<ConsoleState>>,
+    mut panel: ResMut<NotificationPanelState>,
+    mut bell: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<BellButton>)>,
+) {
+    let typing =
+        chat.is_some_and(|chat| chat.is_open()) || console.is_some_and(|console| console.is_open());
+    if keyboard.just_pressed(KeyCode::KeyN) && !typing {
+        panel.open = !panel.open;
+    }
+
+    for (interaction, mut color) in &mut bell {
+        match *interaction {
+            Interaction::Pressed => panel.open = !panel.open,
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+fn update_history_panel(
+    mut commands: Commands,
+    panel_state: Res<NotificationPanelState>,
+    history: Res<NotificationHistory>,
+    icons: Res<Icons>,
+    mut panel: Query<&mut Node, With<HistoryPanel>>,
+    list: Query<Entity, With<HistoryList>>,
+) {
+    if panel_state.is_changed() {
+        for mut node in &mut panel {
+            node.display = if panel_state.open {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+    }
+
+    if !panel_state.open || !(history.is_changed() || panel_state.is_changed()) {
+        return;
+    }
+
+    let Ok(list) = list.single() else {
+        return;
+    };
+
+    commands.entity(list).despawn_children();
+    let entries: Vec<NotificationEntry> = history.entries().rev().cloned().collect();
+    commands.entity(list).with_children(|list| {
+        if entries.is_empty() {
+            list.spawn((
+                Text::new("No notifications"),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+        }
+
+        for entry in entries {
+            let color = entry.severity.color();
+            list.spawn(Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn(icons.node(entry.icon.icon_name(), 14.0, color));
+                row.spawn((
+                    Text::new(format!(
+                        "[{}] {}",
+                        entry.timestamp.format("%H:%M:%S"),
+                        entry.message
+                    )),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            });
+        }
+    });
+}
@@ -1,3 +1,4 @@
+pub mod camera_path;
 pub mod in_game;
 mod in_game_menu;
 mod main_menu;
@@ -5,6 +6,8 @@ mod splashscreen;
 
 use bevy::prelude::*;
 
+use camera_path::CameraPathPlugin;
+
 pub use in_game::InGameScenePlugin;
 pub use in_game_menu::InGameMenuScenePlugin;
 pub use main_menu::MainMenuScenePlugin;
@@ -16,6 +19,7 @@ pub struct ScenePlugin;
 impl Plugin for ScenePlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            CameraPathPlugin,
             SplashscreenScenePlugin,
             MainMenuScenePlugin,
             InGameScenePlugin,
@@ -0,0 +1,588 @@
+//! Cinematic camera paths.
+//!
+//! A [`CameraPath`] is a list of keyframes (position, look-at point, FOV, time) that is
+//! interpolated with Catmull-Rom or Bezier splines. A [`CameraPathPlayer`] on a camera plays
+//! a path once, looped or ping-pong and triggers [`CameraPathMarkerReached`] for every
+//! marker it passes. Paths are built in code or loaded from `*.campath.json` files.
+//!
+//! Used for the main menu backdrop, the splashscreen shot and in-game cutscenes
+//! (see [`PlayCutscene`](super::in_game::cameras::PlayCutscene)).
+
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+use serde::{Deserialize, Serialize};
+
+/// Plugin for camera path assets and playback
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<CameraPath>()
+            .init_asset_loader::<CameraPathLoader>()
+            .add_observer(log_marker)
+            .add_systems(
+                PostUpdate,
+                play_camera_paths.before(TransformSystems::Propagate),
+            );
+    }
+}
+
+/// How the position and look-at point move between keyframes
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PathInterpolation {
+    Linear,
+    /// Smooth curve through all keyframes
+    #[default]
+    CatmullRom,
+    /// Cubic Bezier segments shaped by the keyframe tangents
+    Bezier,
+}
+
+/// A point of a [`CameraPath`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CameraKeyframe {
+    /// Seconds since the start of the path
+    pub time: f32,
+    pub position: Vec3,
+    pub look_at: Vec3,
+    /// Vertical field of view in degrees
+    #[serde(default = "default_fov")]
+    pub fov: f32,
+    /// Direction and speed of the path at this keyframe, used by [`PathInterpolation::Bezier`].
+    /// Defaults to the Catmull-Rom tangent through the neighbouring keyframes.
+    #[serde(default)]
+    pub tangent: Option<Vec3>,
+    /// Easing of the segment that starts at this keyframe
+    #[serde(default = "default_ease")]
+    pub ease: EaseFunction,
+}
+
+fn default_fov() -> f32 {
+    45.0
+}
+
+fn default_ease() -> EaseFunction {
+    EaseFunction::Linear
+}
+
+impl CameraKeyframe {
+    pub fn new(time: f32, position: Vec3, look_at: Vec3) -> Self {
+        Self {
+            time,
+            position,
+            look_at,
+            fov: default_fov(),
+            tangent: None,
+            ease: default_ease(),
+        }
+    }
+
+    pub fn with_fov(mut self, fov: f32) -> Self {
+        self.fov = fov;
+        self
+    }
+
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+/// Named point in time of a [`CameraPath`], reported through [`CameraPathMarkerReached`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CameraPathMarker {
+    pub time: f32,
+    pub name: String,
+}
+
+/// Keyframed camera path
+#[derive(Asset, TypePath, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CameraPath {
+    /// Keyframes, sorted by time
+    pub keyframes: Vec<CameraKeyframe>,
+    #[serde(default)]
+    pub markers: Vec<CameraPathMarker>,
+    #[serde(default)]
+    pub interpolation: PathInterpolation,
+    /// Closes the path: seconds from the last keyframe back to the first. The curve stays
+    /// smooth across the seam, which makes looping paths (orbits) seamless.
+    #[serde(default)]
+    pub closing_time: Option<f32>,
+}
+
+/// Camera pose on a [`CameraPath`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPathPose {
+    pub position: Vec3,
+    pub look_at: Vec3,
+    /// Vertical field of view in radians
+    pub fov: f32,
+}
+
+impl CameraPathPose {
+    /// Transform looking from `position` at `look_at`
+    pub fn transform(&self) -> Transform {
+        let transform = Transform::from_translation(self.position);
+        if (self.look_at - self.position).length_squared() > 1e-6 {
+            transform.looking_at(self.look_at, Vec3::Y)
+        } else {
+            transform
+        }
+    }
+}
+
+impl CameraPath {
+    pub fn new(mut keyframes: Vec<CameraKeyframe>) -> Self {
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Self {
+            keyframes,
+            markers: Vec::new(),
+            interpolation: PathInterpolation::default(),
+            closing_time: None,
+        }
+    }
+
+    /// Closes the path, see [`CameraPath::closing_time`]
+    pub fn closed(mut self, closing_time: f32) -> Self {
+        self.closing_time = Some(closing_time);
+        self
+    }
+
+    /// Time of the first keyframe
+    pub fn start(&self) -> f32 {
+        self.keyframes
+            .first()
+            .map(|key| key.time)
+            .unwrap_or_default()
+    }
+
+    /// Time at which the path ends (including the way back of a closed path)
+    pub fn end(&self) -> f32 {
+        let last = self
+            .keyframes
+            .last()
+            .map(|key| key.time)
+            .unwrap_or_default();
+        match self.closing_time {
+            Some(closing_time) if self.keyframes.len() > 1 => last + closing_time.max(0.0),
+            _ => last,
+        }
+    }
+
+    /// Camera pose at the given path time (clamped to the path)
+    pub fn sample(&self, time: f32) -> Option<CameraPathPose> {
+        let count = self.keyframes.len();
+        let first = self.keyframes.first()?;
+        if count == 1 {
+            return Some(CameraPathPose {
+                position: first.position,
+                look_at: first.look_at,
+                fov: first.fov.to_radians(),
+            });
+        }
+
+        let closed = self.closing_time.is_some();
+        let time = time.clamp(self.start(), self.end());
+        let segments = if closed { count } else { count - 1 };
+
+        // Segment containing `time`, the last one also owns the end of the path
+        let index = (0..segments)
+            .find(|&index| time < self.segment_end(index))
+            .unwrap_or(segments - 1);
+        let start = self.keyframes[index].time;
+        let duration = self.segment_end(index) - start;
+        let local = if duration > 0.0 {
+            ((time - start) / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let key = |offset: isize| -> &CameraKeyframe {
+            let index = index as isize + offset;
+            let index = if closed {
+                index.rem_euclid(count as isize)
+            } else {
+                index.clamp(0, count as isize - 1)
+            };
+            &self.keyframes[index as usize]
+        };
+        let (k0, k1, k2, k3) = (key(-1), key(0), key(1), key(2));
+        let t = k1.ease.sample_clamped(local);
+
+        let position = match self.interpolation {
+            PathInterpolation::Linear => k1.position.lerp(k2.position, t),
+            PathInterpolation::CatmullRom => {
+                catmull_rom(k0.position, k1.position, k2.position, k3.position, t)
+            }
+            PathInterpolation::Bezier => {
+                let out_tangent = k1.tangent.unwrap_or((k2.position - k0.position) * 0.5);
+                let in_tangent = k2.tangent.unwrap_or((k3.position - k1.position) * 0.5);
+                bezier(
+                    k1.position,
+                    k1.position + out_tangent / 3.0,
+                    k2.position - in_tangent / 3.0,
+                    k2.position,
+                    t,
+                )
+            }
+        };
+        let look_at = match self.interpolation {
+            PathInterpolation::Linear => k1.look_at.lerp(k2.look_at, t),
+            PathInterpolation::CatmullRom | PathInterpolation::Bezier => {
+                catmull_rom(k0.look_at, k1.look_at, k2.look_at, k3.look_at, t)
+            }
+        };
+
+        Some(CameraPathPose {
+            position,
+            look_at,
+            fov: k1.fov.lerp(k2.fov, t).to_radians(),
+        })
+    }
+
+    /// End time of the segment starting at keyframe `index`
+    fn segment_end(&self, index: usize) -> f32 {
+        match self.keyframes.get(index + 1) {
+            Some(next) => next.time,
+            None => self.end(),
+        }
+    }
+}
+
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+fn bezier(p0: Vec3, c0: Vec3, c1: Vec3, p1: Vec3, t: f32) -> Vec3 {
+    let u = 1.0 - t;
+    p0 * (u * u * u) + c0 * (3.0 * u * u * t) + c1 * (3.0 * u * t * t) + p1 * (t * t * t)
+}
+
+/// Loads [`CameraPath`]s from `*.campath.json` files
+#[derive(Default, TypePath)]
+pub struct CameraPathLoader;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CameraPathLoaderError {
+    #[error("could not read camera path: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid camera path: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl AssetLoader for CameraPathLoader {
+    type Asset = CameraPath;
+    type Settings = ();
+    type Error = CameraPathLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<CameraPath, CameraPathLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut path: CameraPath = serde_json::from_slice(&bytes)?;
+        path.keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+        Ok(path)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["campath.json"]
+    }
+}
+
+/// What happens when a [`CameraPathPlayer`] reaches the end of its path
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PathPlayback {
+    /// Stop at the end and trigger [`CameraPathFinished`]
+    #[default]
+    Once,
+    /// Start over from the beginning
+    Loop,
+    /// Play backwards to the start, then forwards again
+    PingPong,
+}
+
+/// Plays a [`CameraPath`] on the camera it is attached to.
+///
+/// Writes the `Transform` (and the FOV of a perspective `Projection`) every frame after
+/// `Update`, so it overrides whatever else moves the camera while it is present.
+#[derive(Component, Debug, Clone)]
+pub struct CameraPathPlayer {
+    pub path: Handle<CameraPath>,
+    pub playback: PathPlayback,
+    /// Playback speed factor
+    pub speed: f32,
+    pub paused: bool,
+
+    // Runtime state
+    /// Current path time (None until the path is loaded)
+    pub time: Option<f32>,
+    /// Playing backwards (ping-pong)
+    pub reversed: bool,
+    pub finished: bool,
+}
+
+impl CameraPathPlayer {
+    pub fn new(path: Handle<CameraPath>, playback: PathPlayback) -> Self {
+        Self {
+            path,
+            playback,
+            speed: 1.0,
+            paused: false,
+            time: None,
+            reversed: false,
+            finished: false,
+        }
+    }
+
+    /// Moves the path time by `step` seconds on a path from `start` to `end`, wrapping or
+    /// reversing as the playback asks. Returns the time ranges passed, for the markers.
+    pub fn advance(&mut self, start: f32, end: f32, step: f32) -> Vec<(f32, f32)> {
+        let previous = self.time.unwrap_or(start);
+        let mut current = previous;
+        let mut passed: Vec<(f32, f32)> = Vec::new();
+
+        if self.time.is_none() {
+            passed.push((start, start));
+        }
+        if !self.paused && !self.finished {
+            if self.reversed {
+                current -= step;
+            } else {
+                current += step;
+            }
+
+            match self.playback {
+                PathPlayback::Once => {
+                    current = current.clamp(start, end);
+                    passed.push((previous, current));
+                    if current >= end {
+                        self.finished = true;
+                    }
+                }
+                PathPlayback::Loop => {
+                    let length = end - start;
+                    if current > end && length > 0.0 {
+                        current = start + (current - start).rem_euclid(length);
+                        passed.push((previous, end));
+                        passed.push((start, start));
+                        passed.push((start, current));
+                    } else {
+                        current = current.min(end);
+                        passed.push((previous, current));
+                    }
+                }
+                PathPlayback::PingPong => {
+                    if current > end {
+                        current = (2.0 * end - current).max(start);
+                        self.reversed = true;
+                        passed.push((previous, end));
+                        passed.push((end, current));
+                    } else if current < start {
+                        current = (2.0 * start - current).min(end);
+                        self.reversed = false;
+                        passed.push((previous, start));
+                        passed.push((start, current));
+                    } else {
+                        passed.push((previous, current));
+                    }
+                }
+            }
+        }
+        self.time = Some(current);
+        passed
+    }
+}
+
+/// Event triggered when a [`CameraPathPlayer`] passes a marker of its path
+#[derive(Event, Debug, Clone)]
+pub struct CameraPathMarkerReached {
+    pub camera: Entity,
+    pub marker: String,
+}
+
+/// Event triggered when a [`PathPlayback::Once`] player reached the end of its path
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CameraPathFinished {
+    pub camera: Entity,
+}
+
+fn log_marker(event: On<CameraPathMarkerReached>) {
+    debug!(
+        "Camera {} reached path marker '{}'",
+        event.camera, event.marker
+    );
+}
+
+/// Advances all [`CameraPathPlayer`]s and moves their cameras.
+///
+/// A path that failed to load finishes right away, so nothing waits for it forever.
+pub fn play_camera_paths(
+    mut commands: Commands,
+    time: Res<Time>,
+    paths: Res<Assets<CameraPath>>,
+    asset_server: Res<AssetServer>,
+    mut players: Query<(
+        Entity,
+        &mut CameraPathPlayer,
+        &mut Transform,
+        Option<&mut Projection>,
+    )>,
+) {
+    for (camera, mut player, mut transform, projection) in &mut players {
+        let Some(path) = paths.get(&player.path) else {
+            if !player.finished && asset_server.load_state(&player.path).is_failed() {
+                warn!(
+                    "Camera path {:?} failed to load, skipping it",
+                    player.path.path()
+                );
+                player.finished = true;
+                commands.trigger(CameraPathFinished { camera });
+            }
+            continue;
+        };
+
+        let was_finished = player.finished;
+        let step = time.delta_secs() * player.speed;
+        let passed = player.advance(path.start(), path.end(), step);
+        if player.finished && !was_finished {
+            commands.trigger(CameraPathFinished { camera });
+        }
+        let current = player.time.unwrap_or(path.start());
+
+        for marker in &path.markers {
+            let reached = passed.iter().any(|&(from, to)| {
+                if from == to {
+                    marker.time == from
+                } else if from < to {
+                    marker.time > from && marker.time <= to
+                } else {
+                    marker.time < from && marker.time >= to
+                }
+            });
+            if reached {
+                commands.trigger(CameraPathMarkerReached {
+                    camera,
+                    marker: marker.name.clone(),
+                });
+            }
+        }
+
+        let Some(pose) = path.sample(current) else {
+            continue;
+        };
+        *transform = pose.transform();
+        if let Some(mut projection) = projection {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = pose.fov;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn straight_path(interpolation: PathInterpolation) -> CameraPath {
+        let mut path = CameraPath::new(vec![
+            CameraKeyframe::new(0.0, Vec3::ZERO, Vec3::NEG_Z).with_fov(40.0),
+            CameraKeyframe::new(1.0, Vec3::X, Vec3::NEG_Z).with_fov(60.0),
+            CameraKeyframe::new(2.0, Vec3::X * 2.0, Vec3::NEG_Z).with_fov(60.0),
+        ]);
+        path.interpolation = interpolation;
+        path
+    }
+
+    fn position(path: &CameraPath, time: f32) -> Vec3 {
+        path.sample(time).unwrap().position
+    }
+
+    #[test]
+    fn sample_hits_the_keyframes() {
+        for interpolation in [
+            PathInterpolation::Linear,
+            PathInterpolation::CatmullRom,
+            PathInterpolation::Bezier,
+        ] {
+            let path = straight_path(interpolation);
+            for key in &path.keyframes {
+                assert!(
+                    position(&path, key.time).abs_diff_eq(key.position, 1e-5),
+                    "{interpolation:?} misses the keyframe at {}",
+                    key.time
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sample_clamps_to_the_endpoints() {
+        let path = straight_path(PathInterpolation::CatmullRom);
+        assert_eq!(position(&path, -5.0), Vec3::ZERO);
+        assert!(position(&path, 10.0).abs_diff_eq(Vec3::X * 2.0, 1e-5));
+    }
+
+    #[test]
+    fn sample_blends_fov_and_applies_easing() {
+        let mut path = straight_path(PathInterpolation::Linear);
+        let pose = path.sample(0.5).unwrap();
+        assert!((pose.fov - 50f32.to_radians()).abs() < 1e-5);
+        assert!(pose.position.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+
+        path.keyframes[0].ease = EaseFunction::QuadraticIn;
+        assert!(position(&path, 0.5).abs_diff_eq(Vec3::X * 0.25, 1e-5));
+    }
+
+    #[test]
+    fn closed_path_returns_to_the_first_keyframe() {
+        let path = straight_path(PathInterpolation::CatmullRom).closed(1.0);
+        assert_eq!(path.end(), 3.0);
+        assert!(position(&path, 3.0).abs_diff_eq(Vec3::ZERO, 1e-5));
+    }
+
+    #[test]
+    fn once_stops_at_the_end() {
+        let mut player = CameraPathPlayer::new(Handle::default(), PathPlayback::Once);
+        player.advance(0.0, 2.0, 1.5);
+        assert!(!player.finished);
+        player.advance(0.0, 2.0, 1.5);
+        assert_eq!(player.time, Some(2.0));
+        assert!(player.finished);
+        // Stays at the end once finished
+        player.advance(0.0, 2.0, 1.0);
+        assert_eq!(player.time, Some(2.0));
+    }
+
+    #[test]
+    fn loop_wraps_around() {
+        let mut player = CameraPathPlayer::new(Handle::default(), PathPlayback::Loop);
+        player.time = Some(1.5);
+        let passed = player.advance(0.0, 2.0, 1.0);
+        assert_eq!(player.time, Some(0.5));
+        assert_eq!(passed, vec![(1.5, 2.0), (0.0, 0.0), (0.0, 0.5)]);
+        assert!(!player.finished);
+    }
+
+    #[test]
+    fn ping_pong_reverses_at_both_ends() {
+        let mut player = CameraPathPlayer::new(Handle::default(), PathPlayback::PingPong);
+        player.time = Some(1.5);
+        player.advance(0.0, 2.0, 1.0);
+        assert_eq!(player.time, Some(1.5));
+        assert!(player.reversed);
+
+        player.advance(0.0, 2.0, 2.0);
+        assert_eq!(player.time, Some(0.5));
+        assert!(!player.reversed);
+    }
+}
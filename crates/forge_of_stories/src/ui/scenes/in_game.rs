@@ -1,10 +1,16 @@
 //! In-Game Scene
 //!
-//! Main gameplay scene with HUD, world, cameras, and input handling.
+//! Main gameplay scene with HUD, chat, minimap, player list, interaction prompts, world, cameras,
+//! photo mode and input handling.
 
 pub mod cameras;
+mod chat;
 mod hud;
 mod input;
+pub mod interaction;
+pub(crate) mod minimap;
+pub mod photo_mode;
+pub(crate) mod player_list;
 pub mod world;
 
 use bevy::prelude::*;
@@ -18,9 +24,14 @@ impl Plugin for InGameScenePlugin {
             // Register all sub-plugins
             .add_plugins((
                 hud::InGameHUDPlugin,
+                chat::ChatHudPlugin,
+                minimap::MinimapPlugin,
+                player_list::PlayerListPlugin,
+                interaction::InteractionPlugin,
                 world::InGameWorldPlugin,
                 cameras::InGameCamerasPlugin,
                 input::InGameInputPlugin,
+                photo_mode::PhotoModePlugin,
             ));
     }
 }
@@ -2,27 +2,151 @@
 
 pub(super) mod cursor;
 mod first_person;
+mod free_fly;
+mod isometric;
 mod pan_orbit;
+mod rig;
+mod settings;
+mod shake;
+mod split_screen;
+mod third_person;
 
 use crate::GameState;
-use crate::ui::components::InGameMenuState;
+use crate::networking::LocalPlayer;
+use crate::ui::components::{
+    InGameMenuState, gameplay_input_allowed, map_allows_input, photo_mode_allows_input,
+};
+use crate::ui::debug_overlay::DebugOverlayAppExt;
+use crate::ui::scenes::camera_path::{
+    CameraPath, CameraPathFinished, CameraPathPlayer, PathPlayback, play_camera_paths,
+};
 use crate::utils::cleanup;
+use bevy::camera::visibility::VisibilitySystems;
 use bevy::prelude::*;
 use bevy::transform::TransformSystems;
+use bevy_enhanced_input::prelude::*;
 use cursor::apply_cursor_state;
+use serde::{Deserialize, Serialize};
 
 // Re-export CursorState for use in input module
 pub use cursor::CursorState;
+// Re-export camera state components for diagnostics
+pub use first_person::FirstPersonView;
+pub use free_fly::FreeFlyCamera;
+pub use pan_orbit::{
+    CameraBlocker, CameraBlockers, IgnoreCameraCollision, PanOrbitCamera, PanOrbitCollision,
+    PanOrbitEdgeScroll,
+};
+// Re-export rig configuration (FOV per mode, transition curves)
+pub use rig::{
+    CameraInputUnlocked, CameraRig, CameraTransition, CameraTransitionSettings, TransitionCurve,
+};
+// Re-export persisted camera preferences
+pub use settings::{CameraSettings, TrackpadMode};
+// Re-export camera shake so gameplay code can add trauma
+pub use shake::CameraTrauma;
+// Re-export split-screen pairing of cameras, players and input devices
+pub use split_screen::{LocalPlayerSlot, LocalPlayers, MAX_LOCAL_PLAYERS, PlayerInputDevice};
+pub use third_person::ThirdPersonCamera;
 
 pub struct InGameCamerasPlugin;
 
 /// Camera mode enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CameraMode {
     FirstPerson,
+    ThirdPerson,
     PanOrbit,
+    /// Spectator camera detached from the player (toggled with V)
+    FreeFly,
+    /// Orthographic tactical view with the yaw in 45° steps, shares the pan-orbit state
+    Isometric,
 }
 
+impl CameraMode {
+    /// All modes
+    pub const ALL: [CameraMode; 5] = [
+        CameraMode::FirstPerson,
+        CameraMode::ThirdPerson,
+        CameraMode::PanOrbit,
+        CameraMode::FreeFly,
+        CameraMode::Isometric,
+    ];
+
+    /// Modes in the order they are cycled with C
+    pub const CYCLE: [CameraMode; 4] = [
+        CameraMode::FirstPerson,
+        CameraMode::ThirdPerson,
+        CameraMode::PanOrbit,
+        CameraMode::Isometric,
+    ];
+
+    /// The mode that follows this one when cycling with C
+    pub fn next(self) -> Self {
+        match Self::CYCLE.iter().position(|mode| *mode == self) {
+            Some(index) => Self::CYCLE[(index + 1) % Self::CYCLE.len()],
+            None => Self::CYCLE[0],
+        }
+    }
+
+    /// Whether the local player is controlled while this mode is active
+    pub fn controls_player(self) -> bool {
+        self != CameraMode::FreeFly
+    }
+
+    /// Whether the `PanOrbitCamera` drives the camera in this mode
+    pub fn uses_pan_orbit(self) -> bool {
+        matches!(self, CameraMode::PanOrbit | CameraMode::Isometric)
+    }
+
+    /// Cursor state used while this mode is active (mouse look locks the cursor)
+    pub fn cursor_state(self) -> CursorState {
+        match self {
+            CameraMode::FirstPerson | CameraMode::ThirdPerson | CameraMode::FreeFly => {
+                CursorState::LOCKED
+            }
+            CameraMode::PanOrbit | CameraMode::Isometric => CursorState::FREE,
+        }
+    }
+
+    /// Parses a mode name, ignoring case and `_`/`-` (e.g. `first_person`, `PanOrbit`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        let normalized: String = name
+            .chars()
+            .filter(|c| *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|mode| format!("{mode:?}").to_lowercase() == normalized)
+    }
+}
+
+/// Event requesting a switch of a player's in-game camera to the given mode
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SetCameraMode {
+    pub mode: CameraMode,
+    pub slot: LocalPlayerSlot,
+}
+
+impl SetCameraMode {
+    pub fn for_slot(mode: CameraMode, slot: LocalPlayerSlot) -> Self {
+        Self { mode, slot }
+    }
+}
+
+/// Event starting an in-game cutscene: the InGameCamera follows the path instead of the
+/// active mode until it ends or [`StopCutscene`] is triggered
+#[derive(Event, Debug, Clone)]
+pub struct PlayCutscene {
+    pub path: Handle<CameraPath>,
+    pub playback: PathPlayback,
+}
+
+/// Event ending the running cutscene and blending back to the active camera mode
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StopCutscene;
+
 /// Component that tracks the active camera mode on the camera entity
 #[derive(Component, Debug, Clone, Copy)]
 pub struct ActiveCameraMode {
@@ -39,32 +163,88 @@ impl Default for ActiveCameraMode {
 
 impl Plugin for InGameCamerasPlugin {
     fn build(&self, app: &mut App) {
-        app
+        app.register_debug_marker::<InGameCamera>("InGameCamera")
             // Register PanOrbit resources
             .init_resource::<pan_orbit::ActiveCameraData>()
             .init_resource::<pan_orbit::MouseKeyTracker>()
             .init_resource::<pan_orbit::TouchTracker>()
             .init_resource::<CursorState>()
+            .init_resource::<CameraTransitionSettings>()
+            .init_resource::<CameraSettings>()
+            .init_resource::<LocalPlayers>()
+            .add_input_context::<first_person::FirstPersonContext>()
+            .add_input_context::<pan_orbit::PanOrbitContext>()
+            .add_observer(apply_camera_mode)
+            .add_observer(start_cutscene)
+            .add_observer(finish_cutscene)
+            .add_observer(stop_cutscene)
+            .add_observer(shake::add_camera_trauma)
             // Setup systems
             .add_systems(OnEnter(GameState::InGame), spawn_camera)
             .add_systems(
                 Update,
                 (
                     apply_cursor_state,
+                    // Split-screen cameras and viewports
+                    (
+                        split_screen::assign_player_slots,
+                        split_screen::sync_player_cameras.run_if(resource_changed::<LocalPlayers>),
+                        split_screen::update_viewports,
+                        split_screen::update_ui_camera,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::InGame)),
+                    split_screen::gamepad_look
+                        .run_if(in_state(GameState::InGame))
+                        .run_if(is_menu_inactive)
+                        .run_if(gameplay_input_allowed),
+                    settings::apply_camera_settings
+                        .run_if(in_state(GameState::InGame))
+                        .after(split_screen::sync_player_cameras),
+                    (shake::detect_hard_landings, shake::decay_trauma)
+                        .run_if(in_state(GameState::InGame)),
                     // FirstPerson systems
                     (
-                        first_person::handle_mouse_look,
+                        first_person::handle_mouse_look.run_if(gameplay_input_allowed),
+                        first_person::update_aim.run_if(gameplay_input_allowed),
                         first_person::follow_player,
                         first_person::apply_orientation,
+                        first_person::apply_fov,
                     )
                         .chain()
                         .run_if(in_state(GameState::InGame))
                         .run_if(is_first_person_active)
                         .run_if(is_menu_inactive),
-                    // Toggle system
-                    toggle_camera_mode
+                    // ThirdPerson systems
+                    (
+                        third_person::handle_mouse_look.run_if(gameplay_input_allowed),
+                        third_person::auto_reset,
+                        third_person::follow_player,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::InGame))
+                        .run_if(is_third_person_active)
+                        .run_if(is_menu_inactive),
+                    // Confine the cursor for edge scrolling
+                    pan_orbit::confine_cursor
                         .run_if(in_state(GameState::InGame))
+                        .run_if(is_pan_orbit_active)
+                        .run_if(is_menu_inactive)
+                        .run_if(gameplay_input_allowed),
+                    // FreeFly systems
+                    (
+                        free_fly::handle_input.run_if(gameplay_input_allowed),
+                        free_fly::apply_transform,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::InGame))
+                        .run_if(is_free_fly_active)
                         .run_if(is_menu_inactive),
+                    // Toggle systems
+                    (toggle_camera_mode, toggle_free_fly)
+                        .run_if(in_state(GameState::InGame))
+                        .run_if(is_menu_inactive)
+                        .run_if(gameplay_input_allowed),
                 ),
             )
             .add_systems(
@@ -75,22 +255,52 @@ impl Plugin for InGameCamerasPlugin {
                         pan_orbit::active_viewport_data.run_if(
                             |active_cam: Res<pan_orbit::ActiveCameraData>| !active_cam.manual,
                         ),
-                        (pan_orbit::mouse_key_tracker, pan_orbit::touch_tracker),
+                        (
+                            pan_orbit::mouse_key_tracker,
+                            pan_orbit::touch_tracker,
+                            (pan_orbit::apply_pan_orbit_actions, pan_orbit::edge_scroll)
+                                .run_if(is_menu_inactive)
+                                .run_if(gameplay_input_allowed),
+                        )
+                            .chain(),
+                        isometric::snap_isometric_yaw,
                         pan_orbit::pan_orbit_camera,
+                        pan_orbit::avoid_occlusion,
                     )
                         .chain()
                         .run_if(in_state(GameState::InGame))
                         .run_if(is_pan_orbit_active)
+                        .run_if(map_allows_input)
+                        .run_if(photo_mode_allows_input)
                         .before(TransformSystems::Propagate),
                     // Follow player focus for PanOrbit
                     pan_orbit::follow_local_player_focus
                         .run_if(in_state(GameState::InGame))
-                        .run_if(is_pan_orbit_active),
+                        .run_if(is_pan_orbit_active)
+                        .after(pan_orbit::apply_pan_orbit_actions)
+                        .after(pan_orbit::edge_scroll)
+                        .before(pan_orbit::pan_orbit_camera),
+                    // Mode transition blend, after every mode wrote its pose
+                    rig::blend_camera_transition
+                        .run_if(in_state(GameState::InGame))
+                        .after(pan_orbit::avoid_occlusion)
+                        .before(play_camera_paths)
+                        .before(TransformSystems::Propagate),
+                    // Shake on top of the final pose, frusta are computed from the shaken one
+                    shake::apply_camera_shake
+                        .run_if(in_state(GameState::InGame))
+                        .after(TransformSystems::Propagate)
+                        .before(VisibilitySystems::UpdateFrusta),
                 ),
             )
             .add_systems(
                 OnExit(GameState::InGame),
-                (cleanup::<InGameCamera>, release_cursor),
+                (
+                    cleanup::<InGameCamera>,
+                    cleanup::<split_screen::SplitScreenUiCamera>,
+                    split_screen::reset_local_players,
+                    release_cursor,
+                ),
             );
     }
 }
@@ -98,102 +308,435 @@ impl Plugin for InGameCamerasPlugin {
 #[derive(Component)]
 pub struct InGameCamera;
 
-/// Run condition: Check if FirstPerson mode is active
+/// Run condition: Check if any camera is in FirstPerson mode
 fn is_first_person_active(camera_query: Query<&ActiveCameraMode, With<InGameCamera>>) -> bool {
     camera_query
         .iter()
-        .next()
-        .map(|mode| mode.mode == CameraMode::FirstPerson)
-        .unwrap_or(false)
+        .any(|mode| mode.mode == CameraMode::FirstPerson)
+}
+/// Run condition: Check if any camera is in ThirdPerson mode
+fn is_third_person_active(camera_query: Query<&ActiveCameraMode, With<InGameCamera>>) -> bool {
+    camera_query
+        .iter()
+        .any(|mode| mode.mode == CameraMode::ThirdPerson)
 }
+
 fn is_menu_inactive(menu: Res<InGameMenuState>) -> bool {
     menu.is_closed()
 }
 
-/// Run condition: Check if PanOrbit mode is active
-fn is_pan_orbit_active(camera_query: Query<&ActiveCameraMode, With<InGameCamera>>) -> bool {
+/// Run condition: Check if any camera is in FreeFly mode
+fn is_free_fly_active(camera_query: Query<&ActiveCameraMode, With<InGameCamera>>) -> bool {
     camera_query
         .iter()
-        .next()
-        .map(|mode| mode.mode == CameraMode::PanOrbit)
-        .unwrap_or(false)
+        .any(|mode| mode.mode == CameraMode::FreeFly)
+}
+
+/// Run condition: Check if any camera is driven by the PanOrbitCamera (PanOrbit, Isometric)
+fn is_pan_orbit_active(camera_query: Query<&ActiveCameraMode, With<InGameCamera>>) -> bool {
+    camera_query.iter().any(|mode| mode.mode.uses_pan_orbit())
+}
+
+fn spawn_camera(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    local_players: Res<LocalPlayers>,
+    mut cursor: ResMut<CursorState>,
+) {
+    for (slot, device) in local_players.iter() {
+        let mode = spawn_camera_rig(
+            &mut commands,
+            &settings,
+            slot,
+            device,
+            local_players.count(),
+        );
+        if device == PlayerInputDevice::KeyboardMouse {
+            *cursor = mode.cursor_state();
+        }
+    }
 }
 
-fn spawn_camera(mut commands: Commands, mut cursor: ResMut<CursorState>) {
-    // Spawn a single camera with both FirstPerson and PanOrbit components
-    // Start in FirstPerson mode
-    commands.spawn((
+/// Spawns the camera of one local player, returns the mode it starts in
+fn spawn_camera_rig(
+    commands: &mut Commands,
+    settings: &CameraSettings,
+    slot: LocalPlayerSlot,
+    device: PlayerInputDevice,
+    player_count: usize,
+) -> CameraMode {
+    // A single camera rig carrying the state of every mode; `apply_camera_settings` writes the
+    // preferences into it once spawned
+    let fp_view = first_person::FirstPersonView::default();
+    let tp_camera = third_person::ThirdPersonCamera::default();
+    let mut orbit = pan_orbit::PanOrbitCamera::default();
+    let edge_scroll = pan_orbit::PanOrbitEdgeScroll::default();
+    let follow = pan_orbit::PanOrbitFollow::default();
+    let free_fly = free_fly::FreeFlyCamera::default();
+    let rig = CameraRig::default();
+    let shake = shake::CameraShake::default();
+    let mut isometric = isometric::IsometricView::default();
+
+    // Start in the preferred mode; free fly needs a player to take off from
+    let mode = if settings.default_mode.controls_player() {
+        settings.default_mode
+    } else {
+        CameraMode::FirstPerson
+    };
+    if mode.uses_pan_orbit() {
+        // Focus is moved onto the player once it is replicated
+        orbit.activate(Vec3::new(0.0, 1.0, 0.0), Vec3::NEG_Z);
+    }
+    let projection = if mode == CameraMode::Isometric {
+        isometric.enter(&mut orbit);
+        isometric::orthographic_projection(rig.pan_orbit_fov)
+    } else {
+        // The aspect ratio is only known once the viewport is; first person corrects its FOV
+        // every frame
+        let aspect_ratio = PerspectiveProjection::default().aspect_ratio;
+        Projection::Perspective(PerspectiveProjection {
+            fov: rig.fov(mode, &fp_view, aspect_ratio),
+            aspect_ratio,
+            ..default()
+        })
+    };
+
+    let mut camera = commands.spawn((
         Camera3d::default(),
         Transform::from_xyz(0.0, 1.7, 0.0).looking_at(Vec3::new(0.0, 1.7, -1.0), Vec3::Y),
+        projection,
         InGameCamera,
-        ActiveCameraMode::default(), // Starts in FirstPerson
-        first_person::FirstPersonView::default(),
-        pan_orbit::PanOrbitCamera::default(),
-        Name::new("InGame Camera"),
+        ActiveCameraMode { mode },
+        (rig, shake),
+        (slot, device, device.gamepad_device(player_count)),
+        (fp_view, first_person::FirstPersonContext),
+        (
+            orbit,
+            isometric,
+            follow,
+            pan_orbit::PanOrbitCollision::default(),
+            edge_scroll,
+            pan_orbit::PanOrbitContext,
+        ),
+        tp_camera,
+        free_fly,
+        Name::new(format!("InGame Camera {}", slot.0 + 1)),
     ));
 
-    // Start with locked cursor for FirstPerson
-    *cursor = CursorState::LOCKED;
+    match device {
+        PlayerInputDevice::KeyboardMouse => {
+            camera.insert((
+                actions!(
+                    first_person::FirstPersonContext[(
+                        Action::<first_person::AimDownSights>::default(),
+                        bindings![MouseButton::Right],
+                    )]
+                ),
+                pan_orbit::pan_orbit_actions(),
+            ));
+        }
+        PlayerInputDevice::Gamepad(_) => {
+            camera.insert((
+                actions!(
+                    first_person::FirstPersonContext[(
+                        Action::<first_person::AimDownSights>::default(),
+                        bindings![GamepadButton::LeftTrigger2],
+                    )]
+                ),
+                pan_orbit::pan_orbit_gamepad_actions(),
+            ));
+        }
+    }
+
+    mode
 }
 
+/// C cycles the camera of the keyboard & mouse player, the north face button (Y/Triangle)
+/// the one of a gamepad player.
 fn toggle_camera_mode(
+    mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    camera_query: Query<
+        (&ActiveCameraMode, &LocalPlayerSlot, &PlayerInputDevice),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    for (active_mode, slot, device) in &camera_query {
+        let pressed = match *device {
+            PlayerInputDevice::KeyboardMouse => keyboard.just_pressed(KeyCode::KeyC),
+            PlayerInputDevice::Gamepad(gamepad) => gamepads
+                .get(gamepad)
+                .is_ok_and(|gamepad| gamepad.just_pressed(GamepadButton::North)),
+        };
+        if pressed {
+            commands.trigger(SetCameraMode::for_slot(active_mode.mode.next(), *slot));
+        }
+    }
+}
+
+/// V enters free fly, or returns to the mode it was entered from (keyboard & mouse player only).
+fn toggle_free_fly(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    camera_query: Query<
+        (
+            &ActiveCameraMode,
+            &free_fly::FreeFlyCamera,
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
+        ),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    if !keyboard.just_pressed(free_fly::FREE_FLY_KEY) {
+        return;
+    }
+    let Some((active_mode, free_fly, slot, _)) = camera_query
+        .iter()
+        .find(|(.., device)| **device == PlayerInputDevice::KeyboardMouse)
+    else {
+        return;
+    };
+
+    let mode = if active_mode.mode == CameraMode::FreeFly {
+        free_fly.return_mode
+    } else {
+        CameraMode::FreeFly
+    };
+    commands.trigger(SetCameraMode::for_slot(mode, *slot));
+}
+
+/// Observer: switches the in-game camera of the requested player to the requested mode.
+///
+/// The new mode starts looking where the camera currently looks; the zoom of the pan-orbit
+/// camera is kept between visits. Instead of snapping, a [`CameraTransition`] blends from the current pose to the new one.
+#[allow(clippy::too_many_arguments)]
+fn apply_camera_mode(
+    event: On<SetCameraMode>,
+    mut commands: Commands,
+    settings: Res<CameraTransitionSettings>,
     mut camera_query: Query<
         (
+            Entity,
             &mut ActiveCameraMode,
-            &mut Transform,
+            &Transform,
+            &mut Projection,
+            &CameraRig,
             &mut first_person::FirstPersonView,
             &mut pan_orbit::PanOrbitCamera,
+            &mut pan_orbit::PanOrbitFollow,
+            &mut isometric::IsometricView,
+            &mut third_person::ThirdPersonCamera,
+            &mut free_fly::FreeFlyCamera,
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
         ),
         With<InGameCamera>,
     >,
+    local_players: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
     mut cursor: ResMut<CursorState>,
+    mut mouse_key_tracker: ResMut<pan_orbit::MouseKeyTracker>,
+    mut touch_tracker: ResMut<pan_orbit::TouchTracker>,
 ) {
-    if keyboard.just_pressed(KeyCode::KeyC) {
-        let Some((mut active_mode, mut transform, mut fp_view, mut pan_orbit)) =
-            camera_query.iter_mut().next()
-        else {
-            return;
-        };
+    let Some((
+        entity,
+        mut active_mode,
+        transform,
+        mut projection,
+        rig,
+        mut fp_view,
+        mut pan_orbit,
+        mut follow,
+        mut isometric,
+        mut third_person,
+        mut free_fly,
+        _,
+        device,
+    )) = camera_query
+        .iter_mut()
+        .find(|(.., slot, _)| **slot == event.slot)
+    else {
+        return;
+    };
 
-        // Toggle mode
-        match active_mode.mode {
-            CameraMode::FirstPerson => {
-                // Switch to PanOrbit
-                active_mode.mode = CameraMode::PanOrbit;
-
-                // Reset PanOrbit to default values
-                *pan_orbit = pan_orbit::PanOrbitCamera::default();
-                pan_orbit.focus = Vec3::new(0.0, 1.0, 0.0);
-                pan_orbit.target_focus = Vec3::new(0.0, 1.0, 0.0);
-                pan_orbit.target_radius = 5.0;
-                pan_orbit.radius = Some(5.0);
-
-                // Set initial PanOrbit transform
-                *transform = Transform::from_xyz(0.0, 3.0, 5.0)
-                    .looking_at(Vec3::new(0.0, 1.0, 0.0), Vec3::Y);
-
-                // Free cursor for PanOrbit
-                *cursor = CursorState::FREE;
-            }
-            CameraMode::PanOrbit => {
-                // Switch to FirstPerson
-                active_mode.mode = CameraMode::FirstPerson;
+    let previous = active_mode.mode;
+    if previous == event.mode {
+        return;
+    }
+    let player = event.slot.find(local_players.iter());
 
-                // Reset FirstPerson to default values
-                *fp_view = first_person::FirstPersonView::default();
+    // The new mode keeps looking the way the camera looks right now
+    let (look_yaw, look_pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
 
-                // Set initial FirstPerson transform
-                *transform = Transform::from_xyz(0.0, 1.7, 0.0)
-                    .looking_at(Vec3::new(0.0, 1.7, -1.0), Vec3::Y);
+    if previous == CameraMode::Isometric {
+        isometric.leave(&mut pan_orbit);
+    }
 
-                // Lock cursor for FirstPerson
-                *cursor = CursorState::LOCKED;
+    match event.mode {
+        CameraMode::PanOrbit | CameraMode::Isometric => {
+            // Between the two orbit modes focus, zoom and yaw stay, so does the framed area
+            if !previous.uses_pan_orbit() {
+                // Orbit around the player from behind the current view, keep the zoom
+                let focus = player.map(|player| player.translation).unwrap_or_default()
+                    + Vec3::new(0.0, 1.0, 0.0);
+                pan_orbit.activate(focus, *transform.forward());
+                // Start following from the player again, without a pan offset
+                follow.anchor = None;
+                follow.attach();
+            }
+            if event.mode == CameraMode::Isometric {
+                isometric.enter(&mut pan_orbit);
             }
         }
+        CameraMode::ThirdPerson => {
+            third_person.look_along(look_yaw, look_pitch);
+        }
+        CameraMode::FirstPerson => {
+            // The player turns towards the view with the next movement input
+            fp_view.look_along(look_yaw, look_pitch);
+            // Don't count the way back as movement for the head bob
+            fp_view.last_player_position = None;
+        }
+        CameraMode::FreeFly => {
+            // Take off from the current camera pose
+            free_fly.start_at(transform.translation, look_yaw, look_pitch);
+            free_fly.return_mode = previous;
+        }
+    }
+
+    active_mode.mode = event.mode;
+    // Cursor and pointer input belong to the keyboard & mouse player
+    if *device == PlayerInputDevice::KeyboardMouse {
+        *cursor = event.mode.cursor_state();
+
+        // Drop input gathered for the previous mode
+        *mouse_key_tracker = default();
+        *touch_tracker = default();
+    }
+
+    let aspect_ratio = rig::projection_aspect_ratio(&projection);
+    let target_fov = rig.fov(event.mode, &fp_view, aspect_ratio);
+
+    // Switch the projection instantly, blending positions means nothing across it
+    if (previous == CameraMode::Isometric) != (event.mode == CameraMode::Isometric) {
+        *projection = if event.mode == CameraMode::Isometric {
+            isometric::orthographic_projection(rig.pan_orbit_fov)
+        } else {
+            Projection::Perspective(PerspectiveProjection {
+                fov: target_fov,
+                aspect_ratio,
+                ..default()
+            })
+        };
+        commands.entity(entity).remove::<CameraTransition>();
+        return;
+    }
+
+    let from_fov = rig::projection_fov(&projection).unwrap_or(target_fov);
+    commands.entity(entity).insert(CameraTransition::new(
+        *transform,
+        from_fov,
+        target_fov,
+        settings.curve(previous, event.mode),
+    ));
+}
+
+/// Observer: hands every InGameCamera over to a cutscene path.
+fn start_cutscene(
+    event: On<PlayCutscene>,
+    mut commands: Commands,
+    camera_query: Query<Entity, With<InGameCamera>>,
+) {
+    for entity in &camera_query {
+        commands
+            .entity(entity)
+            .remove::<CameraTransition>()
+            .insert(CameraPathPlayer::new(event.path.clone(), event.playback));
+    }
+}
+
+/// Observer: a cutscene played once ends by itself.
+fn finish_cutscene(
+    event: On<CameraPathFinished>,
+    mut commands: Commands,
+    camera_query: Query<(), With<InGameCamera>>,
+) {
+    if camera_query.contains(event.camera) {
+        commands.trigger(StopCutscene);
+    }
+}
+
+/// Observer: gives the InGameCameras back to their active modes, blending from the cutscene
+/// pose.
+fn stop_cutscene(
+    _event: On<StopCutscene>,
+    mut commands: Commands,
+    settings: Res<CameraTransitionSettings>,
+    camera_query: Query<
+        (
+            Entity,
+            &ActiveCameraMode,
+            &Transform,
+            &Projection,
+            &CameraRig,
+            &first_person::FirstPersonView,
+        ),
+        (With<InGameCamera>, With<CameraPathPlayer>),
+    >,
+) {
+    for (entity, active_mode, transform, projection, rig, fp_view) in &camera_query {
+        let mode = active_mode.mode;
+        let target_fov = rig.fov(mode, fp_view, rig::projection_aspect_ratio(projection));
+        let from_fov = rig::projection_fov(projection).unwrap_or(target_fov);
+        commands
+            .entity(entity)
+            .remove::<CameraPathPlayer>()
+            .insert(CameraTransition::new(
+                *transform,
+                from_fov,
+                target_fov,
+                settings.curve(mode, mode),
+            ));
     }
 }
 
 fn release_cursor(mut cursor: ResMut<CursorState>) {
     *cursor = CursorState::FREE;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_cycles_through_the_cycle_modes() {
+        let mut mode = CameraMode::CYCLE[0];
+        for expected in CameraMode::CYCLE.iter().cycle().skip(1).take(8) {
+            mode = mode.next();
+            assert_eq!(mode, *expected);
+        }
+    }
+
+    #[test]
+    fn next_leaves_free_fly_for_the_first_cycle_mode() {
+        assert!(!CameraMode::CYCLE.contains(&CameraMode::FreeFly));
+        assert_eq!(CameraMode::FreeFly.next(), CameraMode::CYCLE[0]);
+    }
+
+    #[test]
+    fn every_mode_parses_from_its_name() {
+        for mode in CameraMode::ALL {
+            assert_eq!(CameraMode::from_name(&format!("{mode:?}")), Some(mode));
+        }
+        assert_eq!(
+            CameraMode::from_name("first_person"),
+            Some(CameraMode::FirstPerson)
+        );
+        assert_eq!(CameraMode::from_name("Free-Fly"), Some(CameraMode::FreeFly));
+        assert_eq!(CameraMode::from_name("orbit"), None);
+    }
+}
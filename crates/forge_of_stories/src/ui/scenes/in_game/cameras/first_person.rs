@@ -1,10 +1,18 @@
 // scenes/in_game/cameras/first_person.rs
+//
+// First-person camera at the LocalPlayer's eye height. Defaults follow ReadCamera.md
+// (FOV, smoothing, rotation lag, head bob, ADS).
 
-use super::InGameCamera;
+use super::{
+    ActiveCameraMode, CameraInputUnlocked, CameraMode, CameraTransition, InGameCamera,
+    LocalPlayerSlot, PlayerInputDevice,
+};
 use crate::networking::LocalPlayer;
+use crate::ui::scenes::camera_path::CameraPathPlayer;
 use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
-use std::f32::consts::{FRAC_PI_2, TAU};
+use bevy_enhanced_input::prelude::*;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
 
@@ -13,6 +21,41 @@ pub struct FirstPersonView {
     pub yaw: f32,
     pub pitch: f32,
     pub sensitivity: f32,
+    /// Moving the mouse up looks down
+    pub invert_y: bool,
+    /// Horizontal field of view (radians, 75–100°), converted to the projection's vertical one
+    /// with the aspect ratio of the viewport
+    pub fov: f32,
+    /// Horizontal field of view while aiming down sights (radians, 60–70°)
+    pub aim_fov: f32,
+    /// Seconds to blend between `fov` and `aim_fov` (0.15–0.3)
+    pub aim_transition: f32,
+    /// Mouse input smoothing (0 = raw input, 0.05–0.2 = smooth)
+    pub smoothing: f32,
+    /// How fast the view follows yaw/pitch (1/s, 5–15); 0 disables the lag
+    pub rotation_lag_speed: f32,
+    /// Height of the eyes above the player origin
+    pub eye_height: f32,
+    /// Camera offset in front of the head to avoid clipping (0.05–0.15 m)
+    pub head_offset: f32,
+    /// Head bob amplitude at `head_bob_speed` (0.01–0.03); 0 disables the bob
+    pub head_bob_intensity: f32,
+    /// Bob cycles (two steps) per metre walked
+    pub head_bob_frequency: f32,
+    /// Horizontal speed (m/s) at which the bob reaches full intensity
+    pub head_bob_speed: f32,
+
+    // Runtime state
+    /// Orientation currently shown (lags behind yaw/pitch)
+    pub current_yaw: f32,
+    pub current_pitch: f32,
+    pub smoothed_delta: Vec2,
+    /// Aim blend, 0 = hip, 1 = fully aimed
+    pub aim: f32,
+    pub bob_phase: f32,
+    /// Smoothed horizontal speed of the player
+    pub bob_speed: f32,
+    pub last_player_position: Option<Vec3>,
 }
 
 impl Default for FirstPersonView {
@@ -21,51 +64,259 @@ impl Default for FirstPersonView {
             yaw: 0.0,
             pitch: 0.0,
             sensitivity: 0.002,
+            invert_y: false,
+            fov: 90f32.to_radians(),
+            aim_fov: 65f32.to_radians(),
+            aim_transition: 0.2,
+            smoothing: 0.1,
+            rotation_lag_speed: 15.0,
+            eye_height: 1.7,
+            head_offset: 0.1,
+            head_bob_intensity: 0.02,
+            head_bob_frequency: 0.7,
+            head_bob_speed: 4.0,
+            current_yaw: 0.0,
+            current_pitch: 0.0,
+            smoothed_delta: Vec2::ZERO,
+            aim: 0.0,
+            bob_phase: 0.0,
+            bob_speed: 0.0,
+            last_player_position: None,
         }
     }
 }
 
+impl FirstPersonView {
+    /// Looks along the given yaw/pitch immediately, without rotation lag
+    pub fn look_along(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = yaw.rem_euclid(TAU);
+        self.pitch = pitch.clamp(-PITCH_LIMIT, PITCH_LIMIT);
+        self.current_yaw = self.yaw;
+        self.current_pitch = self.pitch;
+        self.smoothed_delta = Vec2::ZERO;
+    }
+
+    /// Turns the view by a mouse-style delta (radians, positive y looks down)
+    pub fn turn(&mut self, delta: Vec2) {
+        self.yaw = (self.yaw - delta.x).rem_euclid(TAU);
+        self.pitch = (self.pitch - delta.y).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    /// Horizontal field of view for the current aim blend
+    pub fn current_horizontal_fov(&self) -> f32 {
+        let t = EaseFunction::CubicInOut.sample_clamped(self.aim);
+        self.fov.lerp(self.aim_fov, t)
+    }
+
+    /// Vertical field of view for the current aim blend, as written into the projection
+    pub fn current_fov(&self, aspect_ratio: f32) -> f32 {
+        vertical_fov(self.current_horizontal_fov(), aspect_ratio)
+    }
+}
+
+/// Vertical field of view showing `horizontal` across a viewport of `aspect_ratio` (width / height)
+pub fn vertical_fov(horizontal: f32, aspect_ratio: f32) -> f32 {
+    2.0 * ((horizontal / 2.0).tan() / aspect_ratio.max(f32::EPSILON)).atan()
+}
+
+/// Input context for first-person actions, lives on the InGameCamera
+#[derive(Component, Default)]
+pub struct FirstPersonContext;
+
+/// Hold to aim down sights (narrows the FOV)
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct AimDownSights;
+
+/// Mouse look for the keyboard & mouse player
 pub fn handle_mouse_look(
+    time: Res<Time>,
     mut mouse_motion: MessageReader<MouseMotion>,
-    mut camera: Query<&mut FirstPersonView, With<InGameCamera>>,
+    mut camera: Query<
+        (&mut FirstPersonView, &ActiveCameraMode, &PlayerInputDevice),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
 ) {
-    let Ok(mut view) = camera.single_mut() else {
+    let Some(mut view) = camera
+        .iter_mut()
+        .find(|(_, active_mode, device)| {
+            active_mode.mode == CameraMode::FirstPerson
+                && **device == PlayerInputDevice::KeyboardMouse
+        })
+        .map(|(view, _, _)| view)
+    else {
         for _ in mouse_motion.read() {}
         return;
     };
 
-    for motion in mouse_motion.read() {
-        let delta = motion.delta * view.sensitivity;
-        view.yaw = (view.yaw - delta.x).rem_euclid(TAU);
-        view.pitch = (view.pitch - delta.y).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    let raw: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+    // Low-pass on the mouse delta: `smoothing` is the share carried over per 60 Hz frame
+    let keep = view
+        .smoothing
+        .clamp(0.0, 0.95)
+        .powf(time.delta_secs() * 60.0);
+    view.smoothed_delta = raw.lerp(view.smoothed_delta, keep);
+    if view.smoothed_delta.length_squared() < 1e-6 {
+        view.smoothed_delta = Vec2::ZERO;
+        return;
+    }
+
+    // Slower turning while the FOV is narrowed
+    let sensitivity = view.sensitivity * view.current_horizontal_fov() / view.fov;
+    let mut delta = view.smoothed_delta * sensitivity;
+    if view.invert_y {
+        delta.y = -delta.y;
+    }
+    view.turn(delta);
+}
+
+/// Moves the aim blend of every camera towards the state of its [`AimDownSights`] action.
+pub fn update_aim(
+    time: Res<Time>,
+    actions: Query<(&Action<AimDownSights>, &ActionOf<FirstPersonContext>)>,
+    mut cameras: Query<
+        (Entity, &mut FirstPersonView, &ActiveCameraMode),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    for (entity, mut view, active_mode) in &mut cameras {
+        if active_mode.mode != CameraMode::FirstPerson {
+            continue;
+        }
+
+        let aiming = actions
+            .iter()
+            .any(|(action, action_of)| **action_of == entity && **action);
+        let target = if aiming { 1.0 } else { 0.0 };
+        let step = if view.aim_transition > 0.0 {
+            time.delta_secs() / view.aim_transition
+        } else {
+            1.0
+        };
+        if view.aim != target {
+            view.aim = if aiming {
+                (view.aim + step).min(1.0)
+            } else {
+                (view.aim - step).max(0.0)
+            };
+        }
     }
 }
 
 pub fn follow_player(
-    local_player: Query<&Transform, (With<LocalPlayer>, Without<InGameCamera>)>,
-    mut camera: Query<&mut Transform, With<InGameCamera>>,
+    time: Res<Time>,
+    local_players: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
+    mut cameras: Query<
+        (
+            &mut FirstPersonView,
+            &mut Transform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+        ),
+        With<InGameCamera>,
+    >,
 ) {
-    let Ok(player_transform) = local_player.single() else {
-        return;
-    };
+    let dt = time.delta_secs();
 
-    let Ok(mut camera_transform) = camera.single_mut() else {
-        return;
-    };
+    for (mut view, mut camera_transform, active_mode, slot) in &mut cameras {
+        if active_mode.mode != CameraMode::FirstPerson {
+            continue;
+        }
+        let Some(player_transform) = slot.find(local_players.iter()) else {
+            continue;
+        };
+
+        let player_pos = player_transform.translation;
 
-    let player_pos = *player_transform;
+        // Horizontal speed from the replicated position, smoothed against network jitter
+        if dt > 0.0 {
+            let moved = view
+                .last_player_position
+                .map(|last| (player_pos - last).with_y(0.0).length())
+                .unwrap_or_default();
+            let speed = moved / dt;
+            view.bob_speed = view.bob_speed.lerp(speed, 1.0 - (-8.0 * dt).exp());
+        }
+        view.last_player_position = Some(player_pos);
+
+        let yaw = Quat::from_rotation_y(view.current_yaw);
+        let mut offset = Vec3::Y * view.eye_height + yaw * Vec3::NEG_Z * view.head_offset;
+
+        if view.head_bob_intensity > 0.0 && view.head_bob_speed > 0.0 {
+            let strength = (view.bob_speed / view.head_bob_speed).min(1.0);
+            view.bob_phase = (view.bob_phase + view.bob_speed * view.head_bob_frequency * TAU * dt)
+                .rem_euclid(TAU);
+            // Vertical bob once per step, lateral sway once per cycle
+            let amplitude = view.head_bob_intensity * strength;
+            offset += Vec3::Y * view.bob_phase.sin().abs() * amplitude
+                + yaw * Vec3::X * view.bob_phase.cos() * amplitude * 0.5;
+        }
 
-    camera_transform.translation = player_pos.translation + Vec3::new(0.0, 1.7, 0.0);
+        camera_transform.translation = player_pos + offset;
+    }
 }
 
 pub fn apply_orientation(
-    mut camera: Query<(&FirstPersonView, &mut Transform), With<InGameCamera>>,
+    time: Res<Time>,
+    mut cameras: Query<
+        (&mut FirstPersonView, &mut Transform, &ActiveCameraMode),
+        With<InGameCamera>,
+    >,
 ) {
-    let Ok((view, mut transform)) = camera.single_mut() else {
-        return;
-    };
+    for (mut view, mut transform, active_mode) in &mut cameras {
+        if active_mode.mode != CameraMode::FirstPerson {
+            continue;
+        }
+
+        if view.rotation_lag_speed > 0.0 {
+            let t = 1.0 - (-view.rotation_lag_speed * time.delta_secs()).exp();
+            // Shortest way around the circle
+            let yaw_delta = (view.yaw - view.current_yaw + PI).rem_euclid(TAU) - PI;
+            let pitch_delta = view.pitch - view.current_pitch;
+            view.current_yaw = (view.current_yaw + yaw_delta * t).rem_euclid(TAU);
+            view.current_pitch += pitch_delta * t;
+        } else {
+            view.current_yaw = view.yaw;
+            view.current_pitch = view.pitch;
+        }
+
+        let yaw = Quat::from_rotation_y(view.current_yaw);
+        let pitch = Quat::from_rotation_x(view.current_pitch);
+        transform.rotation = yaw * pitch;
+    }
+}
+
+/// Writes the (aim-adjusted) field of view into the projection. The transition blend or a
+/// cutscene path owns the FOV of a camera while it runs.
+#[allow(clippy::type_complexity)]
+pub fn apply_fov(
+    mut cameras: Query<
+        (&FirstPersonView, &mut Projection, &ActiveCameraMode),
+        (
+            With<InGameCamera>,
+            Without<CameraTransition>,
+            Without<CameraPathPlayer>,
+        ),
+    >,
+) {
+    for (view, mut projection, active_mode) in &mut cameras {
+        if active_mode.mode != CameraMode::FirstPerson {
+            continue;
+        }
 
-    let yaw = Quat::from_rotation_y(view.yaw);
-    let pitch = Quat::from_rotation_x(view.pitch);
-    transform.rotation = yaw * pitch;
+        let (current, fov) = match projection.as_ref() {
+            Projection::Perspective(perspective) => {
+                (perspective.fov, view.current_fov(perspective.aspect_ratio))
+            }
+            _ => continue,
+        };
+        if current != fov {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = fov;
+            }
+        }
+    }
 }
@@ -0,0 +1,175 @@
+// scenes/in_game/cameras/free_fly.rs
+//
+// Free-fly spectator camera (noclip), detached from the LocalPlayer. Used for level
+// inspection and bug reproduction; player input is not sent while it is active.
+
+use super::{
+    ActiveCameraMode, CameraInputUnlocked, CameraMode, InGameCamera, LocalPlayerSlot,
+    PlayerInputDevice,
+};
+use crate::networking::LocalPlayer;
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
+/// Pixel scroll events per scroll line
+const PIXELS_PER_LINE: f32 = 100.0;
+
+/// Key that toggles the free-fly camera
+pub const FREE_FLY_KEY: KeyCode = KeyCode::KeyV;
+/// Key that moves the free-fly camera back to the player
+pub const TELEPORT_KEY: KeyCode = KeyCode::KeyT;
+
+#[derive(Component)]
+pub struct FreeFlyCamera {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub sensitivity: f32,
+    pub invert_y: bool,
+    /// Flight speed (m/s), adjusted with the mouse wheel
+    pub speed: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// Speed factor per scroll line
+    pub speed_step: f32,
+    /// Speed factor while Shift is held
+    pub boost_multiplier: f32,
+
+    // Runtime state
+    pub position: Vec3,
+    /// Mode to return to when leaving free fly with the toggle key
+    pub return_mode: CameraMode,
+}
+
+impl Default for FreeFlyCamera {
+    fn default() -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            sensitivity: 0.002,
+            invert_y: false,
+            speed: 8.0,
+            min_speed: 1.0,
+            max_speed: 100.0,
+            speed_step: 1.2,
+            boost_multiplier: 4.0,
+            position: Vec3::ZERO,
+            return_mode: CameraMode::FirstPerson,
+        }
+    }
+}
+
+impl FreeFlyCamera {
+    /// Starts flying from the given pose
+    pub fn start_at(&mut self, position: Vec3, yaw: f32, pitch: f32) {
+        self.position = position;
+        self.yaw = yaw.rem_euclid(TAU);
+        self.pitch = pitch.clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw) * Quat::from_rotation_x(self.pitch)
+    }
+}
+
+/// Mouse look, speed adjustment, WASD/QE flight and teleport back to the player.
+/// Only the keyboard & mouse player can fly.
+#[allow(clippy::type_complexity)]
+pub fn handle_input(
+    time: Res<Time<Real>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_motion: MessageReader<MouseMotion>,
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    local_players: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
+    mut camera: Query<
+        (
+            &mut FreeFlyCamera,
+            &ActiveCameraMode,
+            &PlayerInputDevice,
+            &LocalPlayerSlot,
+        ),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    let Some((mut view, _, _, slot)) = camera.iter_mut().find(|(_, active_mode, device, _)| {
+        active_mode.mode == CameraMode::FreeFly && **device == PlayerInputDevice::KeyboardMouse
+    }) else {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        return;
+    };
+
+    for motion in mouse_motion.read() {
+        let mut delta = motion.delta * view.sensitivity;
+        if view.invert_y {
+            delta.y = -delta.y;
+        }
+        view.yaw = (view.yaw - delta.x).rem_euclid(TAU);
+        view.pitch = (view.pitch - delta.y).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    for wheel in mouse_wheel.read() {
+        let lines = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / PIXELS_PER_LINE,
+        };
+        view.speed =
+            (view.speed * view.speed_step.powf(lines)).clamp(view.min_speed, view.max_speed);
+    }
+
+    if keyboard.just_pressed(TELEPORT_KEY) {
+        if let Some(player) = slot.find(local_players.iter()) {
+            let (yaw, _, _) = player.rotation.to_euler(EulerRot::YXZ);
+            view.start_at(player.translation + Vec3::new(0.0, 1.7, 0.0), yaw, 0.0);
+        }
+        return;
+    }
+
+    // Fly along the view direction, Q/E straight down/up
+    let rotation = view.rotation();
+    let mut direction = Vec3::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        direction += rotation * Vec3::NEG_Z;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        direction -= rotation * Vec3::NEG_Z;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        direction += rotation * Vec3::X;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        direction -= rotation * Vec3::X;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        direction += Vec3::Y;
+    }
+    if keyboard.pressed(KeyCode::KeyQ) {
+        direction -= Vec3::Y;
+    }
+
+    let boost = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        view.boost_multiplier
+    } else {
+        1.0
+    };
+    // Real time, so the camera keeps flying while the game is paused
+    let velocity = direction.normalize_or_zero() * view.speed * boost;
+    view.position += velocity * time.delta_secs();
+}
+
+pub fn apply_transform(
+    mut cameras: Query<(&FreeFlyCamera, &mut Transform, &ActiveCameraMode), With<InGameCamera>>,
+) {
+    for (view, mut transform, active_mode) in &mut cameras {
+        if active_mode.mode != CameraMode::FreeFly {
+            continue;
+        }
+
+        transform.translation = view.position;
+        transform.rotation = view.rotation();
+    }
+}
@@ -0,0 +1,147 @@
+// scenes/in_game/cameras/isometric.rs
+//
+// Isometric (tactical) view: the pan-orbit camera with an orthographic projection, a fixed
+// pitch and the yaw snapped to 45° steps. Both modes share the PanOrbitCamera state, so panning,
+// following and zooming (the radius becomes the orthographic scale) work the same.
+// The orthographic projection shows as much height as the pan-orbit perspective does at the
+// focus distance, which keeps the framed area when switching between the two.
+
+use super::pan_orbit::PanOrbitCamera;
+use super::{ActiveCameraMode, CameraMode, InGameCamera};
+use bevy::camera::ScalingMode;
+use bevy::prelude::*;
+use std::f32::consts::FRAC_PI_4;
+
+/// Depth of the orthographic view volume; the camera sits half of it behind the focus
+const VIEW_DEPTH: f32 = 200.0;
+
+/// Isometric view state of an InGameCamera
+#[derive(Component, Debug, Clone)]
+pub struct IsometricView {
+    /// Downward angle of the view (radians), the true isometric angle by default
+    pub pitch: f32,
+    /// Yaw step of Q/E and the right stick, None keeps the yaw fixed
+    pub yaw_step: Option<f32>,
+    /// Snapped yaw the camera turns to
+    yaw: f32,
+    /// Turn input gathered towards the next step while it is held
+    pending: f32,
+    turning: bool,
+    /// Yaw and pitch limits of the pan-orbit camera before entering, restored on leave
+    saved_limits: Option<OrbitLimits>,
+}
+
+/// Yaw upper/lower and pitch upper/lower limits of a [`PanOrbitCamera`]
+type OrbitLimits = [Option<f32>; 4];
+
+impl Default for IsometricView {
+    fn default() -> Self {
+        Self {
+            pitch: (1.0 / 2f32.sqrt()).atan(),
+            yaw_step: Some(FRAC_PI_4),
+            yaw: 0.0,
+            pending: 0.0,
+            turning: false,
+            saved_limits: None,
+        }
+    }
+}
+
+impl IsometricView {
+    fn snap(&self, yaw: f32) -> f32 {
+        let step = self.yaw_step.unwrap_or(FRAC_PI_4);
+        (yaw / step).round() * step
+    }
+
+    /// Turns the pan-orbit camera to the nearest step of its yaw and the isometric pitch.
+    pub(super) fn enter(&mut self, pan_orbit: &mut PanOrbitCamera) {
+        self.yaw = self.snap(pan_orbit.target_yaw);
+        self.pending = 0.0;
+        self.turning = false;
+        // Entering twice must not save our own lock
+        if self.saved_limits.is_none() {
+            self.saved_limits = Some([
+                pan_orbit.yaw_upper_limit,
+                pan_orbit.yaw_lower_limit,
+                pan_orbit.pitch_upper_limit,
+                pan_orbit.pitch_lower_limit,
+            ]);
+        }
+        self.lock(pan_orbit);
+        pan_orbit.force_update = true;
+    }
+
+    /// Restores the yaw and pitch limits the pan-orbit camera had before [`Self::enter`].
+    pub(super) fn leave(&mut self, pan_orbit: &mut PanOrbitCamera) {
+        let [yaw_upper, yaw_lower, pitch_upper, pitch_lower] =
+            self.saved_limits.take().unwrap_or_default();
+        pan_orbit.yaw_upper_limit = yaw_upper;
+        pan_orbit.yaw_lower_limit = yaw_lower;
+        pan_orbit.pitch_upper_limit = pitch_upper;
+        pan_orbit.pitch_lower_limit = pitch_lower;
+        pan_orbit.force_update = true;
+    }
+
+    /// Pins the targets; the limits make `pan_orbit_camera` drop mouse and touch orbiting
+    fn lock(&self, pan_orbit: &mut PanOrbitCamera) {
+        pan_orbit.target_yaw = self.yaw;
+        pan_orbit.target_pitch = self.pitch;
+        pan_orbit.yaw_upper_limit = Some(self.yaw);
+        pan_orbit.yaw_lower_limit = Some(self.yaw);
+        pan_orbit.pitch_upper_limit = Some(self.pitch);
+        pan_orbit.pitch_lower_limit = Some(self.pitch);
+    }
+}
+
+/// Orthographic projection as high at every distance as a perspective one with `fov` is at
+/// distance 1; with the radius as scale it frames the same area as the pan-orbit perspective.
+pub(super) fn orthographic_projection(fov: f32) -> Projection {
+    Projection::Orthographic(OrthographicProjection {
+        near: 0.0,
+        far: VIEW_DEPTH,
+        scaling_mode: ScalingMode::FixedVertical {
+            viewport_height: 2.0 * (fov * 0.5).tan(),
+        },
+        ..OrthographicProjection::default_3d()
+    })
+}
+
+/// Turns isometric cameras in steps.
+///
+/// The pan-orbit actions move `target_yaw` as usual; pressing turns one step right away,
+/// holding turns another step per step of accumulated input. Runs after the pan-orbit input
+/// and before `pan_orbit_camera`.
+pub(super) fn snap_isometric_yaw(
+    mut cameras: Query<
+        (&ActiveCameraMode, &mut IsometricView, &mut PanOrbitCamera),
+        With<InGameCamera>,
+    >,
+) {
+    for (active_mode, mut view, mut pan_orbit) in &mut cameras {
+        if active_mode.mode != CameraMode::Isometric {
+            continue;
+        }
+
+        if let Some(step) = view.yaw_step {
+            let turn = pan_orbit.target_yaw - view.yaw;
+            if turn.abs() >= step {
+                // A view reset: go to the nearest step
+                view.yaw = view.snap(pan_orbit.target_yaw);
+                view.pending = 0.0;
+            } else if turn != 0.0 && !view.turning {
+                view.yaw += step * turn.signum();
+                view.pending = 0.0;
+            } else {
+                view.pending += turn;
+                if view.pending.abs() >= step {
+                    let direction = view.pending.signum();
+                    view.yaw += step * direction;
+                    view.pending -= step * direction;
+                }
+            }
+            view.turning = turn != 0.0;
+        }
+
+        view.lock(&mut pan_orbit);
+    }
+}
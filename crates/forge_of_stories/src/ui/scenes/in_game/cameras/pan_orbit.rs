@@ -1,49 +1,253 @@
-use super::{ActiveCameraMode, CameraMode, InGameCamera};
+use super::{ActiveCameraMode, CameraInputUnlocked, InGameCamera, LocalPlayerSlot};
 use crate::networking::LocalPlayer;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-/// Fokus dynamisch an LocalPlayer ausrichten (nur wenn PanOrbit aktiv ist)
+/// Wie die PanOrbit-Kamera dem Spieler folgt
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FollowMode {
+    /// Fokus sitzt fest auf dem Spieler, Schwenken wird sofort zurückgenommen
+    HardLock,
+    /// Fokus zieht verzögert nach, sobald der Spieler die Sweet-Spot-Zone verlässt
+    #[default]
+    Lazy,
+    /// Wie HardLock, aber Schwenken löst die Kamera vom Spieler; `AttachCamera` (G) setzt sie
+    /// wieder an
+    Free,
+}
+
+/// Spielerverfolgung der PanOrbit-Kamera
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PanOrbitFollow {
+    pub mode: FollowMode,
+    /// Sweet-Spot-Zone (Lazy) als Anteil der halben Bildgröße, 0.15 = 15 % des Bildschirms
+    pub dead_zone: f32,
+    /// Nachziehgeschwindigkeit (Lazy) pro Sekunde: niedrig = weich, hoch = reaktionsschnell
+    pub lag_speed: f32,
+    /// Zuletzt verfolgter Fokuspunkt am Spieler (None = beim nächsten Mal auf den Spieler setzen)
+    pub anchor: Option<Vec3>,
+    /// Durch Schwenken vom Spieler gelöst (Free)
+    pub detached: bool,
+    /// Zuletzt von der Verfolgung geschriebener `target_focus`; weicht er ab, wurde geschwenkt
+    last_target: Option<Vec3>,
+}
+
+impl Default for PanOrbitFollow {
+    fn default() -> Self {
+        Self {
+            mode: FollowMode::default(),
+            dead_zone: 0.15,
+            lag_speed: 5.0,
+            anchor: None,
+            detached: false,
+            last_target: None,
+        }
+    }
+}
+
+impl PanOrbitFollow {
+    /// Hängt die Kamera wieder an den Spieler, der Fokus gleitet über `target_focus` zurück
+    pub fn attach(&mut self) {
+        self.detached = false;
+        self.last_target = None;
+    }
+}
+
+/// Fokus dynamisch an den LocalPlayer im selben Slot ausrichten (nur wenn PanOrbit aktiv ist).
+/// Je nach `FollowMode` wird nur `target_focus` verschoben, `pan_orbit_camera` blendet den Fokus
+/// dann mit `pan_smoothness` hinterher. Läuft nach den Eingaben und vor `pan_orbit_camera`.
+#[allow(clippy::type_complexity)]
 pub(super) fn follow_local_player_focus(
-    local_player: Query<&Transform, (With<LocalPlayer>, Without<InGameCamera>)>,
-    mut cameras: Query<(&ActiveCameraMode, &mut PanOrbitCamera), With<InGameCamera>>,
+    time: Res<Time>,
+    local_players: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
+    mut cameras: Query<
+        (
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+            &Transform,
+            &Projection,
+            &mut PanOrbitCamera,
+            &mut PanOrbitFollow,
+        ),
+        With<InGameCamera>,
+    >,
 ) {
-    // Safely get the camera - might not exist yet or might be multiple
-    let Some((active_mode, mut pan_orbit)) = cameras.iter_mut().next() else {
-        return;
-    };
-
-    // Nur wenn PanOrbit aktiv ist
-    if active_mode.mode != CameraMode::PanOrbit {
-        return;
+    let dt = time.delta_secs();
+
+    for (active_mode, slot, transform, projection, mut pan_orbit, mut follow) in &mut cameras {
+        // Nur wenn PanOrbit oder Isometric aktiv ist
+        if !active_mode.mode.uses_pan_orbit() {
+            continue;
+        }
+
+        // Safely get the player - might not exist yet
+        let Some(player) = slot.find(local_players.iter()) else {
+            continue;
+        };
+
+        // Focus auf Player-Position + Y-Offset (1.0)
+        let focus = player.translation + Vec3::new(0.0, 1.0, 0.0);
+
+        // Erstes Mal nach dem Aktivieren: direkt auf den Spieler setzen
+        if follow.anchor.is_none() {
+            pan_orbit.focus = focus;
+            pan_orbit.target_focus = focus;
+            follow.anchor = Some(focus);
+            follow.last_target = Some(focus);
+            continue;
+        }
+        follow.anchor = Some(focus);
+
+        // Maus, Tasten, Touch und Randscrollen verschieben `target_focus`
+        let panned = follow
+            .last_target
+            .is_some_and(|last| last != pan_orbit.target_focus);
+
+        let target = match follow.mode {
+            FollowMode::HardLock => focus,
+            FollowMode::Free => {
+                if panned {
+                    follow.detached = true;
+                }
+                if follow.detached {
+                    follow.last_target = None;
+                    continue;
+                }
+                focus
+            }
+            FollowMode::Lazy => {
+                let radius = pan_orbit.radius.unwrap_or(pan_orbit.target_radius);
+                let correction = match half_view_size(projection, radius) {
+                    Some(half) => sweet_spot_correction(
+                        focus - pan_orbit.target_focus,
+                        transform,
+                        half,
+                        follow.dead_zone,
+                        radius,
+                    ),
+                    None => focus - pan_orbit.target_focus,
+                };
+                let lag = 1.0 - (-follow.lag_speed.max(0.0) * dt).exp();
+                pan_orbit.target_focus + correction * lag
+            }
+        };
+
+        // Innerhalb der Fokusgrenzen bleiben, sonst sähe das Begrenzen wie Schwenken aus
+        let target = pan_orbit.clamp_focus(target);
+        if pan_orbit.target_focus != target {
+            pan_orbit.target_focus = target;
+        }
+        follow.last_target = Some(target);
     }
+}
 
-    // Safely get the player - might not exist yet
-    let Some(transform) = local_player.iter().next() else {
-        return;
-    };
+/// Halbe sichtbare Breite und Höhe in der Entfernung `distance` vor der Kamera
+fn half_view_size(projection: &Projection, distance: f32) -> Option<Vec2> {
+    match projection {
+        Projection::Perspective(perspective) => {
+            let half_height = distance * (perspective.fov * 0.5).tan();
+            Some(Vec2::new(
+                half_height * perspective.aspect_ratio,
+                half_height,
+            ))
+        }
+        Projection::Orthographic(orthographic) => Some(orthographic.area.half_size()),
+        Projection::Custom(_) => None,
+    }
+}
 
-    let base_translation = *transform;
+/// Verschiebung des Fokus, die den Spieler (um `offset` vom Fokus entfernt) gerade wieder an
+/// den Rand der Sweet-Spot-Zone bringt. Null, solange er darin bleibt.
+fn sweet_spot_correction(
+    offset: Vec3,
+    transform: &Transform,
+    half_view: Vec2,
+    dead_zone: f32,
+    radius: f32,
+) -> Vec3 {
+    let zone = dead_zone.clamp(0.0, 1.0);
+    let outside = |value: f32, limit: f32| value - value.clamp(-limit, limit);
 
-    // Focus auf Player-Position + Y-Offset (1.0)
-    let focus = base_translation.translation + Vec3::new(0.0, 1.0, 0.0);
+    let right = *transform.right();
+    let up = *transform.up();
+    let forward = *transform.forward();
 
-    pan_orbit.focus = focus;
-    pan_orbit.target_focus = focus;
+    // Bildschirmebene, in Bruchteilen der halben Bildgröße
+    let screen_x = offset.dot(right) / half_view.x.max(f32::EPSILON);
+    let screen_y = offset.dot(up) / half_view.y.max(f32::EPSILON);
+    // Auf die Kamera zu oder von ihr weg sieht man kaum, daher dieselbe Zone relativ zum Radius
+    let depth = offset.dot(forward);
+
+    right * outside(screen_x, zone) * half_view.x
+        + up * outside(screen_y, zone) * half_view.y
+        + forward * outside(depth, zone * radius)
+}
+
+/// Radius beim ersten Aktivieren
+const INITIAL_RADIUS: f32 = 4.5;
+/// Minimale Neigung beim Aktivieren, damit die Kamera nicht auf Bodenhöhe startet
+const MIN_ACTIVATION_PITCH: f32 = 0.25;
+const MAX_ACTIVATION_PITCH: f32 = 1.3;
+
+impl PanOrbitCamera {
+    /// Aktiviert die Kamera um `focus`, hinter dem Fokus entlang der Blickrichtung `look`.
+    /// Der Radius bleibt vom letzten Mal erhalten.
+    pub(super) fn activate(&mut self, focus: Vec3, look: Vec3) {
+        let radius = self.radius.unwrap_or(INITIAL_RADIUS);
+        let look = look.try_normalize().unwrap_or(Vec3::NEG_Z);
+        let (yaw, pitch, radius) =
+            util::calculate_from_translation_and_focus(focus - look * radius, focus, self.axis);
+        let pitch = pitch.clamp(MIN_ACTIVATION_PITCH, MAX_ACTIVATION_PITCH);
+
+        self.yaw = Some(yaw);
+        self.pitch = Some(pitch);
+        self.radius = Some(radius);
+        self.target_yaw = yaw;
+        self.target_pitch = pitch;
+        self.target_radius = radius;
+        self.focus = focus;
+        self.target_focus = focus;
+        // Transform sofort neu schreiben, auch ohne Eingabe
+        self.force_update = true;
+    }
+
+    /// Begrenzt einen Fokuspunkt auf `focus_bounds_shape` (wie `pan_orbit_camera`)
+    pub fn clamp_focus(&self, focus: Vec3) -> Vec3 {
+        let origin = self.focus_bounds_origin;
+        match self.focus_bounds_shape {
+            Some(FocusBoundsShape::Cuboid(shape)) => shape.closest_point(focus - origin) + origin,
+            Some(FocusBoundsShape::Sphere(shape)) => shape.closest_point(focus - origin) + origin,
+            None => focus,
+        }
+    }
 }
 
 use std::f32::consts::PI;
 
-use bevy::camera::{CameraUpdateSystems, RenderTarget};
+use bevy::camera::{CameraProjection, CameraUpdateSystems, RenderTarget};
 use bevy::input::gestures::PinchGesture;
 use bevy::input::mouse::MouseWheel;
 use bevy::transform::TransformSystems;
 use bevy::window::{PrimaryWindow, WindowRef};
 
 // Re-export types and functions needed by cameras.rs
+pub use actions::{
+    PanOrbitContext, apply_pan_orbit_actions, pan_orbit_actions, pan_orbit_gamepad_actions,
+};
+pub use collision::{
+    CameraBlocker, CameraBlockers, IgnoreCameraCollision, PanOrbitCollision, avoid_occlusion,
+};
+pub use edge_scroll::{PanOrbitEdgeScroll, confine_cursor, edge_scroll};
 pub use input::{MouseKeyTracker, mouse_key_tracker};
-pub use touch::{TouchControls, TouchGestures, TouchTracker, touch_tracker};
+pub use touch::{TouchControls, TouchGestures, TouchTracker, TwoFingerGesture, touch_tracker};
 use traits::OptionalClamp;
 
+mod actions;
+mod collision;
+mod edge_scroll;
 mod input;
 mod touch;
 mod traits;
@@ -495,11 +699,25 @@ pub(super) fn pan_orbit_camera(
     active_cam: Res<ActiveCameraData>,
     mouse_key_tracker: Res<MouseKeyTracker>,
     touch_tracker: Res<TouchTracker>,
-    mut orbit_cameras: Query<(Entity, &mut PanOrbitCamera, &mut Transform, &mut Projection)>,
+    unlocked: Query<(), CameraInputUnlocked>,
+    mut orbit_cameras: Query<(
+        Entity,
+        &mut PanOrbitCamera,
+        &mut Transform,
+        &mut Projection,
+        Option<&ActiveCameraMode>,
+    )>,
     time_real: Res<Time<Real>>,
     time_virt: Res<Time<Virtual>>,
 ) {
-    for (entity, mut pan_orbit, mut transform, mut projection) in orbit_cameras.iter_mut() {
+    for (entity, mut pan_orbit, mut transform, mut projection, active_mode) in
+        orbit_cameras.iter_mut()
+    {
+        // Split-Screen: Kameras anderer Spieler in anderen Modi nicht überschreiben
+        if active_mode.is_some_and(|active_mode| !active_mode.mode.uses_pan_orbit()) {
+            continue;
+        }
+
         // Closures that apply limits to the yaw, pitch, and zoom values
         let apply_zoom_limits = {
             let zoom_upper_limit = pan_orbit.zoom_upper_limit;
@@ -580,6 +798,7 @@ pub(super) fn pan_orbit_camera(
         // 1 - Get Input
 
         let mut orbit = Vec2::ZERO;
+        let mut twist = 0.0;
         let mut pan = Vec2::ZERO;
         let mut scroll_line = 0.0;
         let mut scroll_pixel = 0.0;
@@ -588,7 +807,7 @@ pub(super) fn pan_orbit_camera(
         // The reason we only skip getting input if the camera is inactive/disabled is because
         // it might still be moving (lerping towards target values) when the user is not
         // actively controlling it.
-        if pan_orbit.enabled && active_cam.entity == Some(entity) {
+        if pan_orbit.enabled && active_cam.entity == Some(entity) && unlocked.contains(entity) {
             let zoom_direction = match pan_orbit.reversed_zoom {
                 true => -1.0,
                 false => 1.0,
@@ -603,32 +822,63 @@ pub(super) fn pan_orbit_camera(
             orbit_button_changed = mouse_key_tracker.orbit_button_changed;
 
             if pan_orbit.touch_enabled {
-                let (touch_orbit, touch_pan, touch_zoom_pixel) = match pan_orbit.touch_controls {
-                    TouchControls::OneFingerOrbit => match touch_tracker.get_touch_gestures() {
-                        TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0),
-                        TouchGestures::OneFinger(one_finger_gestures) => {
-                            (one_finger_gestures.motion, Vec2::ZERO, 0.0)
-                        }
-                        TouchGestures::TwoFinger(two_finger_gestures) => (
-                            Vec2::ZERO,
-                            two_finger_gestures.motion,
-                            two_finger_gestures.pinch * 0.015,
-                        ),
-                    },
-                    TouchControls::TwoFingerOrbit => match touch_tracker.get_touch_gestures() {
-                        TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0),
-                        TouchGestures::OneFinger(one_finger_gestures) => {
-                            (Vec2::ZERO, one_finger_gestures.motion, 0.0)
-                        }
-                        TouchGestures::TwoFinger(two_finger_gestures) => (
-                            two_finger_gestures.motion,
-                            Vec2::ZERO,
-                            two_finger_gestures.pinch * 0.015,
-                        ),
-                    },
-                };
+                let (touch_orbit, touch_pan, touch_zoom_pixel, touch_twist) =
+                    match pan_orbit.touch_controls {
+                        TouchControls::OneFingerOrbit => match touch_tracker.get_touch_gestures() {
+                            TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0, 0.0),
+                            TouchGestures::OneFinger(one_finger_gestures) => {
+                                (one_finger_gestures.motion, Vec2::ZERO, 0.0, 0.0)
+                            }
+                            TouchGestures::TwoFinger(two_finger_gestures) => (
+                                Vec2::ZERO,
+                                two_finger_gestures.motion,
+                                two_finger_gestures.pinch * 0.015,
+                                0.0,
+                            ),
+                        },
+                        TouchControls::TwoFingerOrbit => match touch_tracker.get_touch_gestures() {
+                            TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0, 0.0),
+                            TouchGestures::OneFinger(one_finger_gestures) => {
+                                (Vec2::ZERO, one_finger_gestures.motion, 0.0, 0.0)
+                            }
+                            TouchGestures::TwoFinger(two_finger_gestures) => (
+                                two_finger_gestures.motion,
+                                Vec2::ZERO,
+                                two_finger_gestures.pinch * 0.015,
+                                0.0,
+                            ),
+                        },
+                        // Nur die erkannte Zwei-Finger-Geste wirkt (siehe TwoFingerGesture)
+                        TouchControls::TwistToRotate => match touch_tracker.get_touch_gestures() {
+                            TouchGestures::None => (Vec2::ZERO, Vec2::ZERO, 0.0, 0.0),
+                            TouchGestures::OneFinger(one_finger_gestures) => {
+                                (Vec2::ZERO, one_finger_gestures.motion, 0.0, 0.0)
+                            }
+                            TouchGestures::TwoFinger(two_finger_gestures) => {
+                                match touch_tracker.two_finger_gesture() {
+                                    Some(TwoFingerGesture::Pinch) => (
+                                        Vec2::ZERO,
+                                        Vec2::ZERO,
+                                        two_finger_gestures.pinch * 0.015,
+                                        0.0,
+                                    ),
+                                    Some(TwoFingerGesture::Twist) => {
+                                        (Vec2::ZERO, Vec2::ZERO, 0.0, two_finger_gestures.rotation)
+                                    }
+                                    Some(TwoFingerGesture::Drag) => (
+                                        Vec2::new(0.0, two_finger_gestures.motion.y),
+                                        Vec2::ZERO,
+                                        0.0,
+                                        0.0,
+                                    ),
+                                    None => (Vec2::ZERO, Vec2::ZERO, 0.0, 0.0),
+                                }
+                            }
+                        },
+                    };
 
                 orbit += touch_orbit * pan_orbit.orbit_sensitivity;
+                twist += touch_twist * pan_orbit.orbit_sensitivity;
                 pan += touch_pan * pan_orbit.pan_sensitivity;
                 scroll_pixel += touch_zoom_pixel * zoom_direction * pan_orbit.zoom_sensitivity;
             }
@@ -663,6 +913,11 @@ pub(super) fn pan_orbit_camera(
                 has_moved = true;
             }
         }
+        if twist != 0.0 {
+            // Clockwise twist turns the scene clockwise, i.e. the camera counter-clockwise
+            pan_orbit.target_yaw += twist;
+            has_moved = true;
+        }
         if pan.length_squared() > 0.0 {
             // Make panning distance independent of resolution and FOV,
             if let Some(vp_size) = active_cam.viewport_size {
@@ -678,7 +933,16 @@ pub(super) fn pan_orbit_camera(
                     Projection::Orthographic(ref p) => {
                         pan *= Vec2::new(p.area.width(), p.area.height()) / vp_size;
                     }
-                    Projection::Custom(_) => todo!(),
+                    Projection::Custom(ref p) => {
+                        // Visible extent at distance 1, read from the clip-from-view matrix
+                        let clip_from_view = p.get_clip_from_view();
+                        let extent =
+                            Vec2::new(clip_from_view.x_axis.x, clip_from_view.y_axis.y).recip();
+                        pan *= extent * 2.0 / vp_size;
+                        if let Some(radius) = pan_orbit.radius {
+                            multiplier = radius;
+                        }
+                    }
                 }
                 // Translate by local axes
                 let right = transform.rotation * pan_orbit.axis[0] * -pan.x;
@@ -783,3 +1047,70 @@ pub(super) fn pan_orbit_camera(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HALF_VIEW: Vec2 = Vec2::new(10.0, 5.0);
+    const RADIUS: f32 = 10.0;
+
+    fn correction(offset: Vec3, transform: &Transform, dead_zone: f32) -> Vec3 {
+        sweet_spot_correction(offset, transform, HALF_VIEW, dead_zone, RADIUS)
+    }
+
+    #[test]
+    fn no_correction_inside_the_zone() {
+        let offset = Vec3::new(4.0, -2.0, -3.0);
+        assert_eq!(correction(offset, &Transform::IDENTITY, 0.5), Vec3::ZERO);
+    }
+
+    #[test]
+    fn pulls_the_player_back_to_the_zone_edge() {
+        let transform = Transform::IDENTITY;
+        // Halbe Bildbreite 10, Zone 0.5: Rand bei 5
+        assert!(
+            correction(Vec3::new(8.0, 0.0, 0.0), &transform, 0.5)
+                .abs_diff_eq(Vec3::new(3.0, 0.0, 0.0), 1e-5)
+        );
+        assert!(
+            correction(Vec3::new(0.0, -4.0, 0.0), &transform, 0.5)
+                .abs_diff_eq(Vec3::new(0.0, -1.5, 0.0), 1e-5)
+        );
+        // In die Tiefe relativ zum Radius
+        assert!(
+            correction(Vec3::new(0.0, 0.0, -7.0), &transform, 0.5)
+                .abs_diff_eq(Vec3::new(0.0, 0.0, -2.0), 1e-5)
+        );
+    }
+
+    #[test]
+    fn zero_zone_is_a_hard_lock() {
+        let offset = Vec3::new(1.0, 2.0, 3.0);
+        assert!(correction(offset, &Transform::IDENTITY, 0.0).abs_diff_eq(offset, 1e-5));
+    }
+
+    #[test]
+    fn zone_is_clamped_to_the_screen() {
+        let offset = Vec3::new(15.0, 0.0, 0.0);
+        let transform = Transform::IDENTITY;
+        assert_eq!(
+            correction(offset, &transform, 3.0),
+            correction(offset, &transform, 1.0)
+        );
+    }
+
+    #[test]
+    fn corrected_offset_lies_on_the_zone_of_a_turned_camera() {
+        let transform = Transform::from_xyz(5.0, 8.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y);
+        let offset = Vec3::new(9.0, 3.0, -6.0);
+        let remaining = offset - correction(offset, &transform, 0.4);
+
+        let screen_x = remaining.dot(*transform.right()) / HALF_VIEW.x;
+        let screen_y = remaining.dot(*transform.up()) / HALF_VIEW.y;
+        let depth = remaining.dot(*transform.forward()) / RADIUS;
+        for value in [screen_x, screen_y, depth] {
+            assert!(value.abs() <= 0.4 + 1e-5);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+use super::super::{ActiveCameraMode, CameraInputUnlocked, LocalPlayerSlot, PlayerInputDevice};
+use super::{PanOrbitCamera, PanOrbitFollow, TouchControls, TouchTracker, util};
+use crate::networking::LocalPlayer;
+
+/// Pan speed in radii per second, so panning feels the same at every zoom level
+const PAN_SPEED: f32 = 1.0;
+/// Orbit speed in radians per second at full deflection
+const ORBIT_SPEED: f32 = 2.0;
+/// Zoom rate: the radius changes by a factor of e per second at full deflection
+const ZOOM_SPEED: f32 = 1.5;
+/// Radius and pitch after resetting the view
+const RESET_RADIUS: f32 = 4.5;
+const RESET_PITCH: f32 = 0.45;
+
+/// Input context for keyboard and gamepad control of the `PanOrbitCamera`
+#[derive(Component, Default)]
+pub struct PanOrbitContext;
+
+/// Moves the focus over the ground (WASD, left stick)
+#[derive(InputAction)]
+#[action_output(Vec2)]
+pub struct PanCamera;
+
+/// Orbits around the focus, x = yaw, y = pitch (Q/E, right stick)
+#[derive(InputAction)]
+#[action_output(Vec2)]
+pub struct OrbitCamera;
+
+/// Zooms in for positive values (R/F, triggers)
+#[derive(InputAction)]
+#[action_output(f32)]
+pub struct ZoomCamera;
+
+/// Puts the camera back behind the player (Home, right stick click)
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct ResetCameraView;
+
+/// Re-attaches a camera detached by panning in `FollowMode::Free` (G, left stick click)
+#[derive(InputAction)]
+#[action_output(bool)]
+pub struct AttachCamera;
+
+/// Bindings of the pan-orbit actions for the keyboard & mouse player, spawned with the camera
+pub fn pan_orbit_actions() -> impl Bundle {
+    actions!(
+        PanOrbitContext[
+            (
+                Action::<PanCamera>::default(),
+                DeadZone::default(),
+                Bindings::spawn((Cardinal::wasd_keys(), Axial::left_stick())),
+            ),
+            (
+                Action::<OrbitCamera>::default(),
+                DeadZone::default(),
+                Bindings::spawn((
+                    Bidirectional {
+                        positive: Binding::from(KeyCode::KeyE),
+                        negative: Binding::from(KeyCode::KeyQ),
+                    },
+                    Axial::right_stick(),
+                )),
+            ),
+            (
+                Action::<ZoomCamera>::default(),
+                Bindings::spawn((
+                    Bidirectional {
+                        positive: Binding::from(KeyCode::KeyR),
+                        negative: Binding::from(KeyCode::KeyF),
+                    },
+                    Bidirectional {
+                        positive: Binding::from(GamepadButton::RightTrigger2),
+                        negative: Binding::from(GamepadButton::LeftTrigger2),
+                    },
+                )),
+            ),
+            (
+                Action::<ResetCameraView>::default(),
+                bindings![KeyCode::Home, GamepadButton::RightThumb],
+            ),
+            (
+                Action::<AttachCamera>::default(),
+                bindings![KeyCode::KeyG, GamepadButton::LeftThumb],
+            ),
+        ]
+    )
+}
+
+/// Gamepad-only bindings of the pan-orbit actions, for split-screen players on a gamepad
+pub fn pan_orbit_gamepad_actions() -> impl Bundle {
+    actions!(
+        PanOrbitContext[
+            (
+                Action::<PanCamera>::default(),
+                DeadZone::default(),
+                Bindings::spawn(Axial::left_stick()),
+            ),
+            (
+                Action::<OrbitCamera>::default(),
+                DeadZone::default(),
+                Bindings::spawn(Axial::right_stick()),
+            ),
+            (
+                Action::<ZoomCamera>::default(),
+                Bindings::spawn(Bidirectional {
+                    positive: Binding::from(GamepadButton::RightTrigger2),
+                    negative: Binding::from(GamepadButton::LeftTrigger2),
+                }),
+            ),
+            (
+                Action::<ResetCameraView>::default(),
+                bindings![GamepadButton::RightThumb],
+            ),
+            (
+                Action::<AttachCamera>::default(),
+                bindings![GamepadButton::LeftThumb],
+            ),
+        ]
+    )
+}
+
+/// Feeds the pan-orbit actions of every camera into its target values. A double tap with
+/// `TouchControls::TwistToRotate` resets the view of the keyboard & mouse player like
+/// [`ResetCameraView`]. Resetting also re-attaches a detached camera like [`AttachCamera`].
+///
+/// Runs before `pan_orbit_camera`, which applies the limits and smoothing like for mouse input.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn apply_pan_orbit_actions(
+    time: Res<Time>,
+    mut was_reset: Local<EntityHashSet>,
+    pan: Query<(&Action<PanCamera>, &ActionOf<PanOrbitContext>)>,
+    orbit: Query<(&Action<OrbitCamera>, &ActionOf<PanOrbitContext>)>,
+    zoom: Query<(&Action<ZoomCamera>, &ActionOf<PanOrbitContext>)>,
+    reset: Query<(&Action<ResetCameraView>, &ActionOf<PanOrbitContext>)>,
+    attach: Query<(&Action<AttachCamera>, &ActionOf<PanOrbitContext>)>,
+    touch_tracker: Res<TouchTracker>,
+    local_players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut PanOrbitCamera,
+            &mut PanOrbitFollow,
+            &Transform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+            &PlayerInputDevice,
+        ),
+        (Without<LocalPlayer>, CameraInputUnlocked),
+    >,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut pan_orbit, mut follow, transform, active_mode, slot, device) in &mut cameras {
+        if !active_mode.mode.uses_pan_orbit() || !pan_orbit.enabled {
+            was_reset.remove(&entity);
+            continue;
+        }
+
+        let pan = action_value(&pan, entity);
+        if pan != Vec2::ZERO {
+            // Along the ground, relative to the view direction
+            let up = pan_orbit.axis[1];
+            let forward = transform.forward().reject_from(up).normalize_or_zero();
+            let right = transform.right().reject_from(up).normalize_or_zero();
+            let speed = PAN_SPEED * pan_orbit.target_radius * pan_orbit.pan_sensitivity;
+            pan_orbit.target_focus += (right * pan.x + forward * pan.y) * speed * dt;
+        }
+
+        let orbit = action_value(&orbit, entity);
+        if orbit != Vec2::ZERO {
+            let speed = ORBIT_SPEED * pan_orbit.orbit_sensitivity * dt;
+            pan_orbit.target_yaw -= orbit.x * speed;
+            pan_orbit.target_pitch -= orbit.y * speed;
+        }
+
+        let zoom = action_value(&zoom, entity);
+        if zoom != 0.0 {
+            pan_orbit.target_radius *= (-zoom * ZOOM_SPEED * pan_orbit.zoom_sensitivity * dt).exp();
+        }
+
+        // Holding the key keeps it attached, no edge needed
+        if action_value(&attach, entity) {
+            follow.attach();
+        }
+
+        let reset = action_value(&reset, entity);
+        // Touch input arrives on the device of the keyboard & mouse player
+        let double_tapped = *device == PlayerInputDevice::KeyboardMouse
+            && pan_orbit.touch_enabled
+            && pan_orbit.touch_controls == TouchControls::TwistToRotate
+            && touch_tracker.double_tapped();
+        let was_held = if reset {
+            !was_reset.insert(entity)
+        } else {
+            was_reset.remove(&entity);
+            false
+        };
+        if (reset && !was_held) || double_tapped {
+            if let Some(player) = slot.find(local_players.iter()) {
+                let focus = follow
+                    .anchor
+                    .unwrap_or(player.translation + Vec3::new(0.0, 1.0, 0.0));
+                reset_view(&mut pan_orbit, focus, *player.forward());
+                follow.attach();
+            }
+        }
+    }
+}
+
+/// Value of the action `A` bound to the context on `camera`
+fn action_value<A: InputAction>(
+    actions: &Query<(&Action<A>, &ActionOf<PanOrbitContext>)>,
+    camera: Entity,
+) -> A::Output
+where
+    A::Output: Default + Copy,
+{
+    actions
+        .iter()
+        .find(|(_, action_of)| ***action_of == camera)
+        .map(|(action, _)| **action)
+        .unwrap_or_default()
+}
+
+/// Sets the targets to look along `look` at `focus` from the default distance and height.
+fn reset_view(pan_orbit: &mut PanOrbitCamera, focus: Vec3, look: Vec3) {
+    let (yaw, _, _) = util::calculate_from_translation_and_focus(
+        focus - look * RESET_RADIUS,
+        focus,
+        pan_orbit.axis,
+    );
+    // Turn the short way round, the target yaw may have wound up several turns
+    let current = pan_orbit.target_yaw;
+    pan_orbit.target_yaw = current + ((yaw - current + PI).rem_euclid(TAU) - PI);
+    pan_orbit.target_pitch = RESET_PITCH;
+    pan_orbit.target_radius = RESET_RADIUS;
+    pan_orbit.target_focus = focus;
+}
@@ -0,0 +1,155 @@
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::prelude::*;
+
+use super::super::{ActiveCameraMode, CameraMode};
+use super::PanOrbitCamera;
+use crate::networking::LocalPlayer;
+
+/// Which entities keep the `PanOrbitCamera` from moving through them.
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+pub enum CameraBlockers {
+    /// Every mesh except the local players and entities marked with [`IgnoreCameraCollision`]
+    #[default]
+    AllMeshes,
+    /// Only entities marked with [`CameraBlocker`] (or their children)
+    Marked,
+}
+
+/// Marks an entity (and its children) as blocker for [`CameraBlockers::Marked`].
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct CameraBlocker;
+
+/// Excludes an entity (and its children) from camera collision in [`CameraBlockers::AllMeshes`].
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct IgnoreCameraCollision;
+
+/// Collision and occlusion avoidance for a `PanOrbitCamera`.
+///
+/// When geometry sits between the focus and the camera, the effective radius is shortened so
+/// the camera stays in front of it. Once the view is clear the radius is restored smoothly.
+/// The configured radius of the `PanOrbitCamera` is never modified.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+pub struct PanOrbitCollision {
+    pub enabled: bool,
+    /// Distance kept free between the camera and the blocking geometry.
+    /// Defaults to `0.3`.
+    pub collision_radius: f32,
+    /// The effective radius never gets shorter than this.
+    /// Defaults to `0.5`.
+    pub min_radius: f32,
+    /// How fast the radius grows back once the view is clear (1/s).
+    /// Defaults to `4.0`.
+    pub restore_speed: f32,
+    pub blockers: CameraBlockers,
+    /// Effective (collision-adjusted) radius. Updated automatically.
+    pub current_radius: Option<f32>,
+}
+
+impl Default for PanOrbitCollision {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            collision_radius: 0.3,
+            min_radius: 0.5,
+            restore_speed: 4.0,
+            blockers: CameraBlockers::default(),
+            current_radius: None,
+        }
+    }
+}
+
+/// Places the camera at the collision-adjusted radius on its orbit.
+///
+/// Runs after `pan_orbit_camera`, every frame, so the camera also reacts to a moving focus
+/// and moving geometry while the user is not orbiting.
+pub fn avoid_occlusion(
+    time: Res<Time>,
+    mut ray_cast: MeshRayCast,
+    local_players: Query<(), With<LocalPlayer>>,
+    parents: Query<&ChildOf>,
+    markers: Query<(Has<CameraBlocker>, Has<IgnoreCameraCollision>)>,
+    mut cameras: Query<(
+        &PanOrbitCamera,
+        &mut PanOrbitCollision,
+        &mut Transform,
+        &Projection,
+        Option<&ActiveCameraMode>,
+    )>,
+) {
+    for (pan_orbit, mut collision, mut transform, projection, active_mode) in cameras.iter_mut() {
+        // Cameras of split-screen players in other modes are not ours to move
+        if active_mode.is_some_and(|active_mode| active_mode.mode != CameraMode::PanOrbit) {
+            continue;
+        }
+        // Orthographic cameras sit far away by design and zoom via scale
+        if !matches!(projection, Projection::Perspective(_)) {
+            continue;
+        }
+        let (Some(yaw), Some(pitch), Some(radius)) =
+            (pan_orbit.yaw, pan_orbit.pitch, pan_orbit.radius)
+        else {
+            continue;
+        };
+
+        let axis = pan_orbit.axis;
+        let rotation = Quat::from_axis_angle(axis[1], yaw) * Quat::from_axis_angle(axis[0], -pitch);
+        let back = rotation * Vec3::Z;
+
+        // An entity blocks if it or one of its ancestors matches the configured blockers
+        let blockers = collision.blockers;
+        let filter = |entity: Entity| {
+            let lineage =
+                || std::iter::once(entity).chain(parents.iter_ancestors::<ChildOf>(entity));
+            if lineage().any(|entity| local_players.contains(entity)) {
+                return false;
+            }
+            match blockers {
+                CameraBlockers::AllMeshes => {
+                    !lineage().any(|entity| markers.get(entity).is_ok_and(|(_, ignore)| ignore))
+                }
+                CameraBlockers::Marked => {
+                    lineage().any(|entity| markers.get(entity).is_ok_and(|(blocker, _)| blocker))
+                }
+            }
+        };
+        let settings = MeshRayCastSettings::default().with_filter(&filter);
+
+        let blocked_radius = Dir3::new(back)
+            .ok()
+            .filter(|_| collision.enabled)
+            .and_then(|direction| {
+                ray_cast
+                    .cast_ray(Ray3d::new(pan_orbit.focus, direction), &settings)
+                    .first()
+                    .map(|(_, hit)| hit.distance)
+            })
+            .filter(|distance| *distance < radius + collision.collision_radius)
+            .map(|distance| (distance - collision.collision_radius).max(collision.min_radius));
+
+        let target = blocked_radius.unwrap_or(radius).min(radius);
+        let current = match collision.current_radius {
+            // Pull in immediately so geometry never ends up between camera and focus
+            Some(current) if target < current => target,
+            Some(current) => {
+                let t = 1.0 - (-collision.restore_speed * time.delta_secs()).exp();
+                let restored = current.lerp(target, t);
+                // Snap once close enough to stop writing the transform every frame
+                if (target - restored).abs() < 0.001 {
+                    target
+                } else {
+                    restored
+                }
+            }
+            None => target,
+        };
+        if collision.current_radius != Some(current) {
+            collision.current_radius = Some(current);
+        }
+
+        transform.set_if_neq(Transform {
+            translation: pan_orbit.focus + back * current,
+            rotation,
+            ..*transform
+        });
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use super::super::{
+    ActiveCameraMode, CameraInputUnlocked, CursorState, InGameCamera, PlayerInputDevice,
+};
+use super::PanOrbitCamera;
+
+/// RTS-style edge scrolling for a `PanOrbitCamera`.
+///
+/// While enabled the cursor is confined to the window, and moving it within `margin` of an
+/// edge pans the focus over the ground in that direction.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+pub struct PanOrbitEdgeScroll {
+    pub enabled: bool,
+    /// Distance from the window edges (logical pixels) in which the camera scrolls.
+    /// Defaults to `20.0`.
+    pub margin: f32,
+    /// Scroll speed in radii per second at the very edge.
+    /// Defaults to `1.0`.
+    pub speed: f32,
+}
+
+impl Default for PanOrbitEdgeScroll {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            margin: 20.0,
+            speed: 1.0,
+        }
+    }
+}
+
+/// Pans `target_focus` of the keyboard & mouse player while the cursor is near a window edge.
+#[allow(clippy::type_complexity)]
+pub fn edge_scroll(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<
+        (
+            &mut PanOrbitCamera,
+            &PanOrbitEdgeScroll,
+            &Transform,
+            &ActiveCameraMode,
+            &PlayerInputDevice,
+        ),
+        CameraInputUnlocked,
+    >,
+) {
+    let Some(window) = window.iter().next() else {
+        return;
+    };
+    if !window.focused {
+        return;
+    }
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let size = window.size();
+
+    for (mut pan_orbit, edge_scroll, transform, active_mode, device) in &mut cameras {
+        if !active_mode.mode.uses_pan_orbit() || *device != PlayerInputDevice::KeyboardMouse {
+            continue;
+        }
+        if !edge_scroll.enabled || !pan_orbit.enabled || edge_scroll.margin <= 0.0 {
+            continue;
+        }
+        // Dragging to orbit or pan near the edge should not scroll as well
+        if mouse.any_pressed([pan_orbit.button_orbit, pan_orbit.button_pan]) {
+            continue;
+        }
+
+        // Ramps up from the inner border of the margin to the edge
+        let margin = edge_scroll.margin;
+        let strength = |distance: f32| (1.0 - distance / margin).clamp(0.0, 1.0);
+        let direction = Vec2::new(
+            strength(size.x - cursor.x) - strength(cursor.x),
+            // Window y points down, the top edge scrolls forward
+            strength(cursor.y) - strength(size.y - cursor.y),
+        );
+        if direction == Vec2::ZERO {
+            continue;
+        }
+
+        let up = pan_orbit.axis[1];
+        let forward = transform.forward().reject_from(up).normalize_or_zero();
+        let right = transform.right().reject_from(up).normalize_or_zero();
+        let speed = edge_scroll.speed * pan_orbit.target_radius * time.delta_secs();
+        let focus = pan_orbit.target_focus + (right * direction.x + forward * direction.y) * speed;
+        pan_orbit.target_focus = pan_orbit.clamp_focus(focus);
+    }
+}
+
+/// Confines the cursor to the window while edge scrolling is enabled, frees it otherwise.
+pub fn confine_cursor(
+    mut cursor: ResMut<CursorState>,
+    cameras: Query<
+        (&ActiveCameraMode, &PanOrbitEdgeScroll, &PlayerInputDevice),
+        With<InGameCamera>,
+    >,
+) {
+    // The cursor belongs to the keyboard & mouse player
+    let Some((active_mode, edge_scroll, _)) = cameras
+        .iter()
+        .find(|(_, _, device)| **device == PlayerInputDevice::KeyboardMouse)
+    else {
+        return;
+    };
+    if !active_mode.mode.uses_pan_orbit() {
+        return;
+    }
+    // Leave the cursor alone while something else (e.g. a mode switch) locked it
+    if *cursor != CursorState::FREE && *cursor != CursorState::CONFINED {
+        return;
+    }
+
+    let desired = if edge_scroll.enabled {
+        CursorState::CONFINED
+    } else {
+        CursorState::FREE
+    };
+    cursor.set_if_neq(desired);
+}
@@ -1,11 +1,12 @@
 use bevy::input::touch::Touch;
 use bevy::math::Vec2;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// The control scheme to use for touch input. Given that some touch gestures don't make sense
 /// being changed (e.g. pinch to zoom), there is just a set if different schemes rather than
 /// full customization.
-#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq)]
+#[derive(Reflect, Default, Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TouchControls {
     /// Touch controls where single finger orbits:
     ///  - One finger move: orbit
@@ -18,6 +19,38 @@ pub enum TouchControls {
     ///  - Two finger move: orbit
     ///  - Two finger pinch: zoom
     TwoFingerOrbit,
+    /// Touch controls with separate two finger gestures:
+    ///  - One finger move: pan
+    ///  - Two finger twist: rotate (yaw)
+    ///  - Two finger vertical drag: tilt (pitch)
+    ///  - Two finger pinch: zoom
+    ///  - Double tap: reset the view
+    ///
+    /// Only one two finger gesture is active at a time, picked by whichever passes its threshold
+    /// first (see [`TwoFingerGesture`]).
+    TwistToRotate,
+}
+
+/// Pinch distance change (pixels) before a two finger gesture counts as zooming
+const PINCH_THRESHOLD: f32 = 30.0;
+/// Twist angle (radians) before a two finger gesture counts as rotating
+const TWIST_THRESHOLD: f32 = 0.2;
+/// Vertical midpoint travel (pixels) before a two finger gesture counts as tilting
+const DRAG_THRESHOLD: f32 = 25.0;
+/// Longest touch (seconds) that still counts as a tap
+const TAP_MAX_DURATION: f32 = 0.25;
+/// Furthest a finger may move (pixels) during a tap
+const TAP_MAX_TRAVEL: f32 = 12.0;
+/// Longest pause (seconds) and distance (pixels) between the taps of a double tap
+const DOUBLE_TAP_INTERVAL: f32 = 0.35;
+const DOUBLE_TAP_DISTANCE: f32 = 50.0;
+
+/// Two finger gesture recognised since the second finger touched down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwoFingerGesture {
+    Pinch,
+    Twist,
+    Drag,
 }
 
 /// Holds information about current mobile gestures
@@ -50,7 +83,6 @@ pub struct TwoFingerGestures {
     pub pinch: f32,
     /// The delta angle of the two touches.
     /// Positive values correspond to rotating clockwise.
-    #[allow(dead_code)]
     pub rotation: f32,
 }
 
@@ -59,9 +91,103 @@ pub struct TwoFingerGestures {
 pub struct TouchTracker {
     curr_pressed: (Option<Touch>, Option<Touch>),
     prev_pressed: (Option<Touch>, Option<Touch>),
+    /// Locked two finger gesture and the pinch/twist/drag amounts accumulated until then
+    two_finger_gesture: Option<TwoFingerGesture>,
+    two_finger_travel: Vec3,
+    /// Touch id and start time of a possible tap
+    tap_start: Option<(u64, f32)>,
+    /// Time and position of the last tap
+    last_tap: Option<(f32, Vec2)>,
+    double_tap: bool,
 }
 
 impl TouchTracker {
+    /// The two finger gesture in progress, once one passed its threshold
+    pub fn two_finger_gesture(&self) -> Option<TwoFingerGesture> {
+        self.two_finger_gesture
+    }
+
+    /// Whether a double tap was completed this frame
+    pub fn double_tapped(&self) -> bool {
+        self.double_tap
+    }
+
+    /// Accumulates the two finger motion until one gesture passes its threshold
+    fn classify_two_finger_gesture(&mut self) {
+        if self.two_finger_gesture.is_some() {
+            return;
+        }
+        let TouchGestures::TwoFinger(gestures) = self.get_touch_gestures() else {
+            return;
+        };
+
+        self.two_finger_travel += Vec3::new(gestures.pinch, gestures.rotation, gestures.motion.y);
+        let ratios = [
+            (
+                TwoFingerGesture::Pinch,
+                self.two_finger_travel.x.abs() / PINCH_THRESHOLD,
+            ),
+            (
+                TwoFingerGesture::Twist,
+                self.two_finger_travel.y.abs() / TWIST_THRESHOLD,
+            ),
+            (
+                TwoFingerGesture::Drag,
+                self.two_finger_travel.z.abs() / DRAG_THRESHOLD,
+            ),
+        ];
+        self.two_finger_gesture = ratios
+            .into_iter()
+            .filter(|(_, ratio)| *ratio >= 1.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(gesture, _)| gesture);
+    }
+
+    fn reset_two_finger_gesture(&mut self) {
+        self.two_finger_gesture = None;
+        self.two_finger_travel = Vec3::ZERO;
+    }
+
+    /// Recognises single finger taps and reports a second one close in time and place
+    fn track_taps(&mut self, touches: &Touches, now: f32) {
+        self.double_tap = false;
+
+        for touch in touches.iter_just_pressed() {
+            // Only a lone finger can tap
+            self.tap_start = (touches.iter().count() == 1).then_some((touch.id(), now));
+        }
+        if touches.iter().count() > 1 {
+            self.tap_start = None;
+        }
+
+        for touch in touches.iter_just_released() {
+            let Some((id, start)) = self.tap_start else {
+                continue;
+            };
+            if id != touch.id() {
+                continue;
+            }
+            self.tap_start = None;
+
+            let is_tap = now - start <= TAP_MAX_DURATION
+                && touch.start_position().distance(touch.position()) <= TAP_MAX_TRAVEL;
+            if !is_tap {
+                continue;
+            }
+
+            let position = touch.position();
+            let is_double = self.last_tap.is_some_and(|(time, last)| {
+                now - time <= DOUBLE_TAP_INTERVAL && last.distance(position) <= DOUBLE_TAP_DISTANCE
+            });
+            if is_double {
+                self.double_tap = true;
+                self.last_tap = None;
+            } else {
+                self.last_tap = Some((now, position));
+            }
+        }
+    }
+
     /// Calculate and return mobile gesture data for this frame
     pub fn get_touch_gestures(&self) -> TouchGestures {
         // The below matches only match when the previous and current frames have the same number
@@ -131,8 +257,17 @@ impl TouchTracker {
 }
 
 /// Read touch input and save it in TouchTracker resource for easy consumption by the main system
-pub fn touch_tracker(touches: Res<Touches>, mut touch_tracker: ResMut<TouchTracker>) {
+pub fn touch_tracker(
+    time: Res<Time<Real>>,
+    touches: Res<Touches>,
+    mut touch_tracker: ResMut<TouchTracker>,
+) {
+    touch_tracker.track_taps(&touches, time.elapsed_secs());
+
     let pressed: Vec<&Touch> = touches.iter().collect();
+    if pressed.len() != 2 {
+        touch_tracker.reset_two_finger_gesture();
+    }
 
     match pressed.len() {
         0 => {
@@ -149,7 +284,144 @@ pub fn touch_tracker(touches: Res<Touches>, mut touch_tracker: ResMut<TouchTrack
             let touch2: &Touch = pressed.last().unwrap();
             touch_tracker.prev_pressed = touch_tracker.curr_pressed;
             touch_tracker.curr_pressed = (Some(*touch1), Some(*touch2));
+            touch_tracker.classify_two_finger_gesture();
         }
         _ => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::touch::{TouchInput, TouchPhase};
+
+    fn touch(id: u64, position: Vec2) -> Touch {
+        Touch::from(&TouchInput {
+            phase: TouchPhase::Moved,
+            position,
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id,
+        })
+    }
+
+    /// Moves two fingers to `a` and `b` for the next frame
+    fn two_fingers(tracker: &mut TouchTracker, a: Vec2, b: Vec2) {
+        tracker.prev_pressed = tracker.curr_pressed;
+        tracker.curr_pressed = (Some(touch(0, a)), Some(touch(1, b)));
+        tracker.classify_two_finger_gesture();
+    }
+
+    /// Two fingers around `center`, `distance` apart, turned by `angle` from horizontal
+    fn fingers_around(center: Vec2, distance: f32, angle: f32) -> (Vec2, Vec2) {
+        let offset = Vec2::from_angle(angle) * distance / 2.0;
+        (center - offset, center + offset)
+    }
+
+    #[test]
+    fn one_finger_reports_its_motion() {
+        let mut tracker = TouchTracker::default();
+        tracker.prev_pressed = (Some(touch(0, Vec2::new(10.0, 10.0))), None);
+        tracker.curr_pressed = (Some(touch(0, Vec2::new(15.0, 7.0))), None);
+        let TouchGestures::OneFinger(gestures) = tracker.get_touch_gestures() else {
+            panic!("expected a one finger gesture");
+        };
+        assert_eq!(gestures.motion, Vec2::new(5.0, -3.0));
+    }
+
+    #[test]
+    fn changing_finger_count_reports_no_gesture() {
+        let mut tracker = TouchTracker::default();
+        tracker.prev_pressed = (Some(touch(0, Vec2::ZERO)), None);
+        tracker.curr_pressed = (Some(touch(0, Vec2::ZERO)), Some(touch(1, Vec2::X)));
+        assert!(matches!(tracker.get_touch_gestures(), TouchGestures::None));
+    }
+
+    #[test]
+    fn spreading_fingers_is_a_pinch() {
+        let mut tracker = TouchTracker::default();
+        let center = Vec2::new(200.0, 200.0);
+        for step in 0..=4 {
+            let (a, b) = fingers_around(center, 100.0 + step as f32 * 10.0, 0.0);
+            two_fingers(&mut tracker, a, b);
+        }
+        assert_eq!(tracker.two_finger_gesture(), Some(TwoFingerGesture::Pinch));
+    }
+
+    #[test]
+    fn turning_fingers_is_a_twist() {
+        let mut tracker = TouchTracker::default();
+        let center = Vec2::new(200.0, 200.0);
+        for step in 0..=3 {
+            let (a, b) = fingers_around(center, 100.0, step as f32 * 0.1);
+            two_fingers(&mut tracker, a, b);
+        }
+        assert_eq!(tracker.two_finger_gesture(), Some(TwoFingerGesture::Twist));
+    }
+
+    #[test]
+    fn moving_fingers_up_together_is_a_drag() {
+        let mut tracker = TouchTracker::default();
+        for step in 0..=3 {
+            let center = Vec2::new(200.0, 200.0 - step as f32 * 10.0);
+            let (a, b) = fingers_around(center, 100.0, 0.0);
+            two_fingers(&mut tracker, a, b);
+        }
+        assert_eq!(tracker.two_finger_gesture(), Some(TwoFingerGesture::Drag));
+    }
+
+    #[test]
+    fn small_motions_stay_unclassified() {
+        let mut tracker = TouchTracker::default();
+        let center = Vec2::new(200.0, 200.0);
+        two_fingers(
+            &mut tracker,
+            center - Vec2::X * 50.0,
+            center + Vec2::X * 50.0,
+        );
+        let (a, b) = fingers_around(center + Vec2::Y * 5.0, 110.0, 0.05);
+        two_fingers(&mut tracker, a, b);
+        assert_eq!(tracker.two_finger_gesture(), None);
+    }
+
+    #[test]
+    fn the_first_gesture_stays_locked_until_reset() {
+        let mut tracker = TouchTracker::default();
+        let center = Vec2::new(200.0, 200.0);
+        two_fingers(
+            &mut tracker,
+            center - Vec2::X * 50.0,
+            center + Vec2::X * 50.0,
+        );
+        let (a, b) = fingers_around(center, 140.0, 0.0);
+        two_fingers(&mut tracker, a, b);
+        assert_eq!(tracker.two_finger_gesture(), Some(TwoFingerGesture::Pinch));
+
+        // Twisting a lot afterwards keeps pinching
+        let (a, b) = fingers_around(center, 140.0, 1.0);
+        two_fingers(&mut tracker, a, b);
+        assert_eq!(tracker.two_finger_gesture(), Some(TwoFingerGesture::Pinch));
+
+        tracker.reset_two_finger_gesture();
+        assert_eq!(tracker.two_finger_gesture(), None);
+    }
+
+    #[test]
+    fn rotation_across_vertical_stays_small() {
+        let mut tracker = TouchTracker::default();
+        let center = Vec2::new(200.0, 200.0);
+        let just_left = std::f32::consts::FRAC_PI_2 - 0.01;
+        let just_right = std::f32::consts::FRAC_PI_2 + 0.01;
+        let (a, b) = fingers_around(center, 100.0, just_left);
+        two_fingers(&mut tracker, a, b);
+        let (a, b) = fingers_around(center, 100.0, just_right);
+        two_fingers(&mut tracker, a, b);
+
+        let TouchGestures::TwoFinger(gestures) = tracker.get_touch_gestures() else {
+            panic!("expected a two finger gesture");
+        };
+        assert!((gestures.rotation.abs() - 0.02).abs() < 1e-3);
+        assert!(gestures.pinch.abs() < 1e-3);
+        assert!(gestures.motion.length() < 1e-3);
+    }
+}
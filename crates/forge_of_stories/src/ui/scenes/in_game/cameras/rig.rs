@@ -0,0 +1,201 @@
+// scenes/in_game/cameras/rig.rs
+//
+// Camera rig: the InGameCamera entity carries the state of every mode (FirstPersonView,
+// ThirdPersonCamera, PanOrbitCamera, FreeFlyCamera) and is the single tween target when
+// switching modes.
+// The mode systems keep writing the Transform of the new mode; the blend below moves the
+// camera from the pose at the time of the switch towards that pose (see ReadCamera.md).
+
+use super::{CameraMode, FirstPersonView, InGameCamera};
+use crate::ui::scenes::camera_path::CameraPathPlayer;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Field of view per camera mode (radians). First person has its own on [`FirstPersonView`].
+#[derive(Component, Debug, Clone)]
+pub struct CameraRig {
+    pub third_person_fov: f32,
+    pub pan_orbit_fov: f32,
+    pub free_fly_fov: f32,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            third_person_fov: 70f32.to_radians(),
+            pan_orbit_fov: 45f32.to_radians(),
+            free_fly_fov: 70f32.to_radians(),
+        }
+    }
+}
+
+impl CameraRig {
+    /// Vertical field of view used while the given mode is active; first person converts its
+    /// horizontal one with the viewport's `aspect_ratio`
+    pub fn fov(&self, mode: CameraMode, first_person: &FirstPersonView, aspect_ratio: f32) -> f32 {
+        match mode {
+            CameraMode::FirstPerson => first_person.current_fov(aspect_ratio),
+            CameraMode::ThirdPerson => self.third_person_fov,
+            // Isometric frames the same area orthographically
+            CameraMode::PanOrbit | CameraMode::Isometric => self.pan_orbit_fov,
+            CameraMode::FreeFly => self.free_fly_fov,
+        }
+    }
+}
+
+/// Duration and ease curve of a single mode transition
+#[derive(Debug, Clone, Copy)]
+pub struct TransitionCurve {
+    /// Seconds; 0 switches instantly
+    pub duration: f32,
+    pub ease: EaseFunction,
+}
+
+impl TransitionCurve {
+    pub fn new(duration: f32, ease: EaseFunction) -> Self {
+        Self { duration, ease }
+    }
+}
+
+/// Configures how the camera blends between modes.
+#[derive(Resource, Debug, Clone)]
+pub struct CameraTransitionSettings {
+    /// Used for every transition without an override
+    pub default: TransitionCurve,
+    /// Per-transition overrides, keyed by (from, to)
+    pub overrides: HashMap<(CameraMode, CameraMode), TransitionCurve>,
+}
+
+impl Default for CameraTransitionSettings {
+    fn default() -> Self {
+        let mut overrides = HashMap::new();
+        // Leaving the player's head is slow at first, entering it settles softly
+        overrides.insert(
+            (CameraMode::FirstPerson, CameraMode::PanOrbit),
+            TransitionCurve::new(0.6, EaseFunction::QuadraticInOut),
+        );
+        overrides.insert(
+            (CameraMode::PanOrbit, CameraMode::FirstPerson),
+            TransitionCurve::new(0.6, EaseFunction::CubicInOut),
+        );
+
+        Self {
+            default: TransitionCurve::new(0.4, EaseFunction::CubicInOut),
+            overrides,
+        }
+    }
+}
+
+impl CameraTransitionSettings {
+    /// Curve for a switch from `from` to `to`
+    pub fn curve(&self, from: CameraMode, to: CameraMode) -> TransitionCurve {
+        self.overrides
+            .get(&(from, to))
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Active blend between two camera modes. Removed once the blend is finished.
+#[derive(Component, Debug, Clone)]
+pub struct CameraTransition {
+    /// Pose at the time of the switch
+    pub from: Transform,
+    pub from_fov: f32,
+    /// Latest pose written by the new mode
+    pub target: Transform,
+    pub target_fov: f32,
+    pub elapsed: f32,
+    pub curve: TransitionCurve,
+}
+
+impl CameraTransition {
+    pub fn new(from: Transform, from_fov: f32, target_fov: f32, curve: TransitionCurve) -> Self {
+        Self {
+            from,
+            from_fov,
+            target: from,
+            target_fov,
+            elapsed: 0.0,
+            curve,
+        }
+    }
+
+    /// Eased progress in 0..1
+    pub fn progress(&self) -> f32 {
+        if self.curve.duration <= 0.0 {
+            return 1.0;
+        }
+        self.curve
+            .ease
+            .sample_clamped(self.elapsed / self.curve.duration)
+    }
+}
+
+/// Query filter for cameras that take input. A camera's input is locked while its mode
+/// transition is blending or a cutscene path plays on it; other cameras of a split screen keep
+/// their input.
+pub type CameraInputUnlocked = (Without<CameraTransition>, Without<CameraPathPlayer>);
+
+/// Current vertical field of view of a projection (None for non-perspective projections)
+pub(super) fn projection_fov(projection: &Projection) -> Option<f32> {
+    match projection {
+        Projection::Perspective(perspective) => Some(perspective.fov),
+        _ => None,
+    }
+}
+
+/// Aspect ratio (width / height) a projection was last updated with
+pub(super) fn projection_aspect_ratio(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Perspective(perspective) => perspective.aspect_ratio,
+        Projection::Orthographic(orthographic) if orthographic.area.height() > 0.0 => {
+            orthographic.area.width() / orthographic.area.height()
+        }
+        _ => PerspectiveProjection::default().aspect_ratio,
+    }
+}
+
+/// Blends the camera from the pose at the time of the switch to the pose of the new mode.
+///
+/// Runs after all mode systems: whatever they wrote into the Transform this frame is taken
+/// as the target and then replaced by the interpolated pose.
+pub(super) fn blend_camera_transition(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Projection,
+            &mut CameraTransition,
+        ),
+        With<InGameCamera>,
+    >,
+) {
+    for (entity, mut transform, mut projection, mut transition) in &mut cameras {
+        // Our own write from last frame does not count as a change here
+        if transform.is_changed() {
+            transition.target = *transform;
+        }
+        let target = transition.target;
+
+        transition.elapsed += time.delta_secs();
+        let t = transition.progress();
+        let finished = transition.elapsed >= transition.curve.duration;
+
+        let fov = if finished {
+            *transform = target;
+            commands.entity(entity).remove::<CameraTransition>();
+            transition.target_fov
+        } else {
+            transform.translation = transition.from.translation.lerp(target.translation, t);
+            transform.rotation = transition.from.rotation.slerp(target.rotation, t);
+            transition.from_fov.lerp(transition.target_fov, t)
+        };
+
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = fov;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// scenes/in_game/cameras/settings.rs
+//
+// Player camera preferences, persisted as the "camera" section of the settings file.
+// Applied to every InGameCamera when it is spawned and again whenever they change.
+
+use super::first_person::FirstPersonView;
+use super::free_fly::FreeFlyCamera;
+use super::isometric::IsometricView;
+use super::pan_orbit::{
+    FollowMode, PanOrbitCamera, PanOrbitEdgeScroll, PanOrbitFollow, TouchControls, TrackpadBehavior,
+};
+use super::rig::{CameraRig, CameraTransition};
+use super::shake::CameraShake;
+use super::third_person::ThirdPersonCamera;
+use super::{ActiveCameraMode, CameraMode, InGameCamera};
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Trackpad behaviour of the pan-orbit camera
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TrackpadMode {
+    /// Scrolling zooms, like a mouse wheel
+    #[default]
+    Default,
+    /// Scrolling orbits, Shift pans, Ctrl zooms (like Blender)
+    BlenderLike,
+}
+
+/// Camera preferences of the player.
+///
+/// Sensitivities are multipliers of the built-in defaults (1.0 = default), angles are in degrees.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct CameraSettings {
+    /// Mode the camera starts in when entering a game
+    pub default_mode: CameraMode,
+    /// Inverts vertical mouse look in first person, third person and free fly
+    pub invert_y: bool,
+    /// Mouse look in first person, third person and free fly
+    pub look_sensitivity: f32,
+    pub orbit_sensitivity: f32,
+    pub pan_sensitivity: f32,
+    pub zoom_sensitivity: f32,
+    /// First-person mouse smoothing (0 = raw input)
+    pub look_smoothing: f32,
+    pub orbit_smoothness: f32,
+    pub pan_smoothness: f32,
+    pub zoom_smoothness: f32,
+    /// Horizontal field of view of first person
+    pub first_person_fov: f32,
+    pub third_person_fov: f32,
+    /// Scrolling up zooms out instead of in
+    pub reversed_zoom: bool,
+    pub trackpad: TrackpadMode,
+    /// Pinching on a trackpad zooms, independent of the trackpad mode
+    pub trackpad_pinch_to_zoom: bool,
+    pub trackpad_sensitivity: f32,
+    pub touch_controls: TouchControls,
+    /// Pan the pan-orbit camera by moving the cursor to the window edges (confines the cursor)
+    pub edge_scroll: bool,
+    /// Width of the edge scroll zone in logical pixels
+    pub edge_scroll_margin: f32,
+    pub edge_scroll_speed: f32,
+    /// How the pan-orbit camera follows the player
+    pub follow_mode: FollowMode,
+    /// Lazy follow: sweet spot as share of the half screen (0.15 = 15 % of the screen)
+    pub follow_dead_zone: f32,
+    /// Lazy follow: catch-up rate per second, low = soft, high = responsive
+    pub follow_lag_speed: f32,
+    /// Q/E turn the isometric view in 45° steps; off keeps its yaw fixed
+    pub isometric_rotation: bool,
+    /// Strength of the camera shake (accessibility, 0 = off, up to 2)
+    pub camera_shake: f32,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        let first_person = FirstPersonView::default();
+        let pan_orbit = PanOrbitCamera::default();
+        let rig = CameraRig::default();
+        let edge_scroll = PanOrbitEdgeScroll::default();
+        let follow = PanOrbitFollow::default();
+
+        Self {
+            default_mode: CameraMode::FirstPerson,
+            invert_y: false,
+            look_sensitivity: 1.0,
+            orbit_sensitivity: 1.0,
+            pan_sensitivity: 1.0,
+            zoom_sensitivity: 1.0,
+            look_smoothing: first_person.smoothing,
+            orbit_smoothness: pan_orbit.orbit_smoothness,
+            pan_smoothness: pan_orbit.pan_smoothness,
+            zoom_smoothness: pan_orbit.zoom_smoothness,
+            first_person_fov: first_person.fov.to_degrees(),
+            third_person_fov: rig.third_person_fov.to_degrees(),
+            reversed_zoom: pan_orbit.reversed_zoom,
+            trackpad: TrackpadMode::Default,
+            trackpad_pinch_to_zoom: pan_orbit.trackpad_pinch_to_zoom_enabled,
+            trackpad_sensitivity: 1.0,
+            touch_controls: pan_orbit.touch_controls,
+            edge_scroll: edge_scroll.enabled,
+            edge_scroll_margin: edge_scroll.margin,
+            edge_scroll_speed: edge_scroll.speed,
+            follow_mode: follow.mode,
+            follow_dead_zone: follow.dead_zone,
+            follow_lag_speed: follow.lag_speed,
+            isometric_rotation: IsometricView::default().yaw_step.is_some(),
+            camera_shake: 1.0,
+        }
+    }
+}
+
+/// State components of the camera rig the preferences are written into
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub(super) struct CameraSettingsTarget {
+    first_person: &'static mut FirstPersonView,
+    third_person: &'static mut ThirdPersonCamera,
+    pan_orbit: &'static mut PanOrbitCamera,
+    edge_scroll: &'static mut PanOrbitEdgeScroll,
+    follow: &'static mut PanOrbitFollow,
+    free_fly: &'static mut FreeFlyCamera,
+    rig: &'static mut CameraRig,
+    shake: &'static mut CameraShake,
+    isometric: &'static mut IsometricView,
+}
+
+impl CameraSettings {
+    /// Writes the preferences into the state components of the camera rig.
+    pub(super) fn apply(&self, camera: &mut CameraSettingsTargetItem) {
+        let CameraSettingsTargetItem {
+            first_person,
+            third_person,
+            pan_orbit,
+            edge_scroll,
+            follow,
+            free_fly,
+            rig,
+            shake,
+            isometric,
+        } = camera;
+
+        first_person.sensitivity = FirstPersonView::default().sensitivity * self.look_sensitivity;
+        first_person.invert_y = self.invert_y;
+        first_person.smoothing = self.look_smoothing;
+        first_person.fov = self.first_person_fov.clamp(60.0, 120.0).to_radians();
+
+        third_person.sensitivity = ThirdPersonCamera::default().sensitivity * self.look_sensitivity;
+        third_person.invert_y = self.invert_y;
+
+        free_fly.sensitivity = FreeFlyCamera::default().sensitivity * self.look_sensitivity;
+        free_fly.invert_y = self.invert_y;
+
+        let pan_orbit_default = PanOrbitCamera::default();
+        pan_orbit.orbit_sensitivity = pan_orbit_default.orbit_sensitivity * self.orbit_sensitivity;
+        pan_orbit.pan_sensitivity = pan_orbit_default.pan_sensitivity * self.pan_sensitivity;
+        pan_orbit.zoom_sensitivity = pan_orbit_default.zoom_sensitivity * self.zoom_sensitivity;
+        // Smoothness of 1.0 would never reach the target
+        pan_orbit.orbit_smoothness = self.orbit_smoothness.clamp(0.0, 0.99);
+        pan_orbit.pan_smoothness = self.pan_smoothness.clamp(0.0, 0.99);
+        pan_orbit.zoom_smoothness = self.zoom_smoothness.clamp(0.0, 0.99);
+        pan_orbit.reversed_zoom = self.reversed_zoom;
+        pan_orbit.trackpad_behavior = match self.trackpad {
+            TrackpadMode::Default => TrackpadBehavior::Default,
+            TrackpadMode::BlenderLike => TrackpadBehavior::blender_default(),
+        };
+        pan_orbit.trackpad_pinch_to_zoom_enabled = self.trackpad_pinch_to_zoom;
+        pan_orbit.trackpad_sensitivity =
+            pan_orbit_default.trackpad_sensitivity * self.trackpad_sensitivity;
+        pan_orbit.touch_controls = self.touch_controls;
+
+        edge_scroll.enabled = self.edge_scroll;
+        edge_scroll.margin = self.edge_scroll_margin.max(0.0);
+        edge_scroll.speed = self.edge_scroll_speed;
+
+        if follow.mode != self.follow_mode {
+            follow.mode = self.follow_mode;
+            follow.attach();
+        }
+        follow.dead_zone = self.follow_dead_zone.clamp(0.0, 1.0);
+        follow.lag_speed = self.follow_lag_speed.max(0.0);
+
+        rig.third_person_fov = self.third_person_fov.clamp(60.0, 120.0).to_radians();
+
+        shake.intensity = self.camera_shake.clamp(0.0, 2.0);
+
+        isometric.yaw_step = self
+            .isometric_rotation
+            .then_some(IsometricView::default().yaw_step)
+            .flatten();
+    }
+}
+
+/// Applies the camera settings to newly spawned cameras, and to every camera once they change.
+pub(super) fn apply_camera_settings(
+    settings: Res<CameraSettings>,
+    mut cameras: Query<(
+        Ref<InGameCamera>,
+        &ActiveCameraMode,
+        CameraSettingsTarget,
+        &mut Projection,
+        Has<CameraTransition>,
+    )>,
+) {
+    for (camera, active_mode, mut target, mut projection, transitioning) in &mut cameras {
+        if !settings.is_changed() && !camera.is_added() {
+            continue;
+        }
+        settings.apply(&mut target);
+
+        // First person writes its FOV every frame; the other modes only get it on switch
+        if active_mode.mode != CameraMode::FirstPerson && !transitioning {
+            if let Projection::Perspective(perspective) = projection.as_mut() {
+                perspective.fov = target.rig.fov(
+                    active_mode.mode,
+                    &target.first_person,
+                    perspective.aspect_ratio,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+// scenes/in_game/cameras/shake.rs
+//
+// Trauma based camera shake. Gameplay adds trauma with a CameraTrauma event, it decays over
+// time and the squared trauma drives noise offsets of position and rotation.
+// The shake is only written to the GlobalTransform after transform propagation: the Transform
+// written by the mode systems (and the yaw/pitch they keep) never sees it.
+
+use super::InGameCamera;
+use super::split_screen::LocalPlayerSlot;
+use crate::networking::LocalPlayer;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+
+/// Fall speed (m/s) from which touching down shakes the camera
+const HARD_LANDING_SPEED: f32 = 8.0;
+/// Fall speed at which a landing adds full trauma
+const MAX_LANDING_SPEED: f32 = 20.0;
+/// Vertical speed above which a falling player counts as landed
+const LANDED_SPEED: f32 = -1.0;
+
+/// Shake state of an InGameCamera
+#[derive(Component, Debug, Clone)]
+pub struct CameraShake {
+    /// Current trauma, 0..=1
+    pub trauma: f32,
+    /// Trauma lost per second
+    pub decay: f32,
+    /// Largest offset along the camera's right, up and back axes (meters)
+    pub max_offset: Vec3,
+    /// Largest yaw, pitch and roll (radians)
+    pub max_rotation: Vec3,
+    /// Noise samples per second, higher shakes faster
+    pub frequency: f32,
+    /// Scale from the accessibility setting, 0 disables the shake
+    pub intensity: f32,
+    time: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.2,
+            max_offset: Vec3::new(0.12, 0.12, 0.06),
+            max_rotation: Vec3::new(0.04, 0.04, 0.06),
+            frequency: 15.0,
+            intensity: 1.0,
+            time: 0.0,
+        }
+    }
+}
+
+impl CameraShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Shake strength: squared trauma, so small hits stay subtle
+    pub fn strength(&self) -> f32 {
+        self.trauma * self.trauma * self.intensity
+    }
+
+    /// Local offset of the camera for the current trauma and time
+    fn offset(&self) -> Transform {
+        let strength = self.strength();
+        let t = self.time * self.frequency;
+        let channel = |seed: f32| noise(t + seed * 71.3);
+
+        let translation = Vec3::new(channel(0.0), channel(1.0), channel(2.0)) * self.max_offset;
+        let angles = Vec3::new(channel(3.0), channel(4.0), channel(5.0)) * self.max_rotation;
+
+        Transform {
+            translation: translation * strength,
+            rotation: Quat::from_euler(
+                EulerRot::YXZ,
+                angles.x * strength,
+                angles.y * strength,
+                angles.z * strength,
+            ),
+            ..default()
+        }
+    }
+}
+
+/// Event adding trauma to the camera of one player, or of every player
+#[derive(Event, Debug, Clone, Copy)]
+pub struct CameraTrauma {
+    pub amount: f32,
+    pub slot: Option<LocalPlayerSlot>,
+}
+
+impl CameraTrauma {
+    pub fn all(amount: f32) -> Self {
+        Self { amount, slot: None }
+    }
+
+    pub fn for_slot(slot: LocalPlayerSlot, amount: f32) -> Self {
+        Self {
+            amount,
+            slot: Some(slot),
+        }
+    }
+}
+
+pub(super) fn add_camera_trauma(
+    event: On<CameraTrauma>,
+    mut cameras: Query<(&mut CameraShake, &LocalPlayerSlot), With<InGameCamera>>,
+) {
+    for (mut shake, slot) in &mut cameras {
+        if event.slot.is_none_or(|target| target == *slot) {
+            shake.add_trauma(event.amount);
+        }
+    }
+}
+
+/// Smooth 1D value noise in -1..=1
+fn noise(x: f32) -> f32 {
+    let hash = |i: f32| ((i * 127.1).sin() * 43_758.547).fract() * 2.0 - 1.0;
+    let cell = x.floor();
+    let f = x - cell;
+    let t = f * f * (3.0 - 2.0 * f);
+    hash(cell) + (hash(cell + 1.0) - hash(cell)) * t
+}
+
+/// Lets the trauma decay and advances the noise.
+pub(super) fn decay_trauma(time: Res<Time>, mut cameras: Query<&mut CameraShake>) {
+    let dt = time.delta_secs();
+    for mut shake in &mut cameras {
+        if shake.trauma > 0.0 {
+            shake.trauma = (shake.trauma - shake.decay * dt).max(0.0);
+            shake.time += dt;
+        }
+    }
+}
+
+/// Applies the shake on top of the propagated pose.
+///
+/// The cameras are root entities, so their GlobalTransform is their Transform; rebuilding it from
+/// the Transform every frame keeps the offset from accumulating and restores the pose once the
+/// trauma is gone.
+pub(super) fn apply_camera_shake(
+    mut cameras: Query<(&Transform, &mut GlobalTransform, &CameraShake), With<InGameCamera>>,
+) {
+    for (transform, mut global, shake) in &mut cameras {
+        let pose = if shake.strength() > 0.0 {
+            transform.mul_transform(shake.offset())
+        } else {
+            *transform
+        };
+        global.set_if_neq(GlobalTransform::from(pose));
+    }
+}
+
+/// Adds trauma when a local player touches down after a long fall or jump.
+///
+/// Positions arrive with replication, so the vertical speed is measured between updates rather
+/// than between frames. Keeps per player the last height, its time and the fastest fall since.
+pub(super) fn detect_hard_landings(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut falls: Local<EntityHashMap<(f32, f32, f32)>>,
+    players: Query<(Entity, Ref<Transform>, &LocalPlayerSlot), With<LocalPlayer>>,
+) {
+    let now = time.elapsed_secs();
+    falls.retain(|entity, _| players.contains(*entity));
+
+    for (entity, transform, slot) in &players {
+        if !transform.is_changed() {
+            continue;
+        }
+        let height = transform.translation.y;
+        let Some((last_height, last_time, fastest)) = falls.get_mut(&entity) else {
+            falls.insert(entity, (height, now, 0.0));
+            continue;
+        };
+
+        let dt = now - *last_time;
+        if dt <= 0.0 {
+            continue;
+        }
+        let vertical_speed = (height - *last_height) / dt;
+        *last_height = height;
+        *last_time = now;
+
+        if vertical_speed < LANDED_SPEED {
+            *fastest = fastest.max(-vertical_speed);
+        } else {
+            if *fastest >= HARD_LANDING_SPEED {
+                let impact =
+                    (*fastest - HARD_LANDING_SPEED) / (MAX_LANDING_SPEED - HARD_LANDING_SPEED);
+                commands.trigger(CameraTrauma::for_slot(
+                    *slot,
+                    0.3 + 0.5 * impact.clamp(0.0, 1.0),
+                ));
+            }
+            *fastest = 0.0;
+        }
+    }
+}
@@ -0,0 +1,427 @@
+// scenes/in_game/cameras/split_screen.rs
+//
+// Local split-screen: up to four players on one client. Every player has its own InGameCamera
+// rendering into a part of the window, an input device and a LocalPlayer entity; camera and
+// player are paired by their LocalPlayerSlot.
+
+use super::{
+    ActiveCameraMode, CameraInputUnlocked, CameraMode, CameraSettings, FirstPersonView,
+    InGameCamera, ThirdPersonCamera,
+};
+use crate::networking::LocalPlayer;
+use bevy::camera::Viewport;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_enhanced_input::prelude::*;
+
+/// Most players sharing one screen
+pub const MAX_LOCAL_PLAYERS: usize = 4;
+/// Right stick turn rate (radians per second at full deflection)
+const STICK_LOOK_SPEED: f32 = 3.0;
+const STICK_DEAD_ZONE: f32 = 0.15;
+
+/// Pairs an InGameCamera (and its HUD) with a LocalPlayer entity, 0 = primary player
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalPlayerSlot(pub usize);
+
+impl LocalPlayerSlot {
+    /// Slot of the keyboard & mouse player, who is always present
+    pub const PRIMARY: Self = Self(0);
+
+    pub fn is_primary(self) -> bool {
+        self == Self::PRIMARY
+    }
+
+    /// The entry of this slot among `(item, slot)` pairs, e.g. from a LocalPlayer query
+    pub fn find<'a, T>(
+        self,
+        items: impl IntoIterator<Item = (T, &'a LocalPlayerSlot)>,
+    ) -> Option<T> {
+        items
+            .into_iter()
+            .find(|(_, slot)| **slot == self)
+            .map(|(item, _)| item)
+    }
+}
+
+/// Input device driving a player's camera and movement
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerInputDevice {
+    KeyboardMouse,
+    Gamepad(Entity),
+}
+
+impl PlayerInputDevice {
+    pub fn label(self) -> &'static str {
+        match self {
+            PlayerInputDevice::KeyboardMouse => "Keyboard & Mouse",
+            PlayerInputDevice::Gamepad(_) => "Gamepad",
+        }
+    }
+
+    /// Gamepads whose input reaches this device's actions. The keyboard player takes any
+    /// gamepad while playing alone and none once gamepads are assigned to other players.
+    pub fn gamepad_device(self, player_count: usize) -> GamepadDevice {
+        match self {
+            PlayerInputDevice::KeyboardMouse if player_count > 1 => GamepadDevice::None,
+            PlayerInputDevice::KeyboardMouse => GamepadDevice::Any,
+            PlayerInputDevice::Gamepad(gamepad) => GamepadDevice::Single(gamepad),
+        }
+    }
+}
+
+/// Input devices of the local players, indexed by slot.
+///
+/// The keyboard & mouse player always holds the primary slot; gamepad players join and leave
+/// behind it (see `input::local_players`).
+#[derive(Resource, Debug, Clone)]
+pub struct LocalPlayers {
+    devices: Vec<PlayerInputDevice>,
+}
+
+impl Default for LocalPlayers {
+    fn default() -> Self {
+        Self {
+            devices: vec![PlayerInputDevice::KeyboardMouse],
+        }
+    }
+}
+
+impl LocalPlayers {
+    pub fn count(&self) -> usize {
+        self.devices.len()
+    }
+
+    /// Whether the window is split between several players
+    pub fn is_split(&self) -> bool {
+        self.count() > 1
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (LocalPlayerSlot, PlayerInputDevice)> + '_ {
+        self.devices
+            .iter()
+            .enumerate()
+            .map(|(slot, device)| (LocalPlayerSlot(slot), *device))
+    }
+
+    pub fn slot_of(&self, device: PlayerInputDevice) -> Option<LocalPlayerSlot> {
+        self.devices
+            .iter()
+            .position(|assigned| *assigned == device)
+            .map(LocalPlayerSlot)
+    }
+
+    /// Adds a player using `device`, returns its slot (None when full or already playing)
+    pub fn join(&mut self, device: PlayerInputDevice) -> Option<LocalPlayerSlot> {
+        if self.devices.len() >= MAX_LOCAL_PLAYERS || self.devices.contains(&device) {
+            return None;
+        }
+        self.devices.push(device);
+        Some(LocalPlayerSlot(self.devices.len() - 1))
+    }
+
+    /// Removes the player in `slot`, the players behind it move up one slot.
+    /// The primary player can't leave.
+    pub fn leave(&mut self, slot: LocalPlayerSlot) -> bool {
+        if slot.is_primary() || slot.0 >= self.devices.len() {
+            return false;
+        }
+        self.devices.remove(slot.0);
+        true
+    }
+}
+
+/// Order in which a LocalPlayer entity arrived on this client, the primary player first
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct JoinSequence(u64);
+
+/// Full-window camera that renders the shared UI (chat, menus, notifications) on top of the
+/// player viewports while the screen is split
+#[derive(Component)]
+pub(super) struct SplitScreenUiCamera;
+
+/// Numbers the LocalPlayer entities by the order they joined in.
+///
+/// Every LocalPlayer gets a [`JoinSequence`] when it arrives, so the players behind one that
+/// left move up like their cameras do. The server spawns one player per `JoinLocalPlayer`,
+/// players arriving in the same frame (e.g. on connect) are ordered by entity.
+#[allow(clippy::type_complexity)]
+pub(super) fn assign_player_slots(
+    mut commands: Commands,
+    mut next_sequence: Local<u64>,
+    players: Query<(Entity, Option<&JoinSequence>, Option<&LocalPlayerSlot>), With<LocalPlayer>>,
+) {
+    let mut players: Vec<_> = players.iter().collect();
+    players.sort_by_key(|(entity, sequence, _)| (sequence.is_none(), *sequence, *entity));
+
+    for (index, (entity, sequence, slot)) in players.into_iter().enumerate() {
+        if sequence.is_none() {
+            commands.entity(entity).insert(JoinSequence(*next_sequence));
+            *next_sequence += 1;
+        }
+        if slot != Some(&LocalPlayerSlot(index)) {
+            commands.entity(entity).insert(LocalPlayerSlot(index));
+        }
+    }
+}
+
+/// Spawns a camera for every joined player, renumbers the remaining ones and despawns the
+/// cameras of players that left.
+pub(super) fn sync_player_cameras(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    local_players: Res<LocalPlayers>,
+    mut cameras: Query<
+        (
+            Entity,
+            &mut LocalPlayerSlot,
+            &PlayerInputDevice,
+            &mut GamepadDevice,
+        ),
+        With<InGameCamera>,
+    >,
+) {
+    for (entity, mut slot, device, mut gamepad_device) in &mut cameras {
+        match local_players.slot_of(*device) {
+            Some(assigned) => {
+                slot.set_if_neq(assigned);
+                *gamepad_device = device.gamepad_device(local_players.count());
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    for (slot, device) in local_players.iter() {
+        if !cameras
+            .iter()
+            .any(|(_, _, assigned, _)| *assigned == device)
+        {
+            super::spawn_camera_rig(
+                &mut commands,
+                &settings,
+                slot,
+                device,
+                local_players.count(),
+            );
+        }
+    }
+}
+
+/// Normalized window area of `slot` when `count` players share a window.
+///
+/// Two players split side by side on wide windows and above each other on tall ones; with
+/// three the primary player gets the whole top half.
+fn viewport_area(slot: LocalPlayerSlot, count: usize, wide: bool) -> Rect {
+    match (count, slot.0) {
+        (2, 0) if wide => Rect::new(0.0, 0.0, 0.5, 1.0),
+        (2, _) if wide => Rect::new(0.5, 0.0, 1.0, 1.0),
+        (2, 0) => Rect::new(0.0, 0.0, 1.0, 0.5),
+        (2, _) => Rect::new(0.0, 0.5, 1.0, 1.0),
+        (3, 0) => Rect::new(0.0, 0.0, 1.0, 0.5),
+        (3, 1) | (4, 2) => Rect::new(0.0, 0.5, 0.5, 1.0),
+        (3, _) | (4, 3) => Rect::new(0.5, 0.5, 1.0, 1.0),
+        (4, 0) => Rect::new(0.0, 0.0, 0.5, 0.5),
+        (4, _) => Rect::new(0.5, 0.0, 1.0, 0.5),
+        _ => Rect::new(0.0, 0.0, 1.0, 1.0),
+    }
+}
+
+/// Lays the player cameras out over the window, follows window resizes.
+pub(super) fn update_viewports(
+    local_players: Res<LocalPlayers>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&LocalPlayerSlot, &mut Camera), With<InGameCamera>>,
+) {
+    let Some(window) = window.iter().next() else {
+        return;
+    };
+    let size = window.physical_size();
+    let count = local_players.count();
+
+    for (slot, mut camera) in &mut cameras {
+        // Later players draw on top, the order also keeps the cameras unambiguous
+        let order = slot.0 as isize;
+        if camera.order != order {
+            camera.order = order;
+        }
+
+        let viewport = (count > 1).then(|| {
+            let area = viewport_area(*slot, count, size.x >= size.y);
+            let min = (area.min * size.as_vec2()).as_uvec2();
+            let max = (area.max * size.as_vec2()).as_uvec2();
+            (min, (max - min).max(UVec2::ONE))
+        });
+        let current = camera
+            .viewport
+            .as_ref()
+            .map(|viewport| (viewport.physical_position, viewport.physical_size));
+        if current != viewport {
+            camera.viewport = viewport.map(|(physical_position, physical_size)| Viewport {
+                physical_position,
+                physical_size,
+                ..default()
+            });
+        }
+    }
+}
+
+/// Spawns the shared UI camera while the screen is split, removes it otherwise.
+pub(super) fn update_ui_camera(
+    mut commands: Commands,
+    local_players: Res<LocalPlayers>,
+    ui_camera: Query<Entity, With<SplitScreenUiCamera>>,
+) {
+    match (local_players.is_split(), ui_camera.iter().next()) {
+        (true, None) => {
+            commands.spawn((
+                Camera2d,
+                Camera {
+                    order: MAX_LOCAL_PLAYERS as isize,
+                    clear_color: ClearColorConfig::None,
+                    ..default()
+                },
+                IsDefaultUiCamera,
+                SplitScreenUiCamera,
+                Name::new("Split Screen UI Camera"),
+            ));
+        }
+        (false, Some(entity)) => commands.entity(entity).despawn(),
+        _ => {}
+    }
+}
+
+/// Right stick look for gamepad players in first and third person. Pan-orbit cameras use
+/// their `PanOrbitContext` actions instead.
+#[allow(clippy::type_complexity)]
+pub(super) fn gamepad_look(
+    time: Res<Time>,
+    gamepads: Query<&Gamepad>,
+    mut cameras: Query<
+        (
+            &PlayerInputDevice,
+            &ActiveCameraMode,
+            &mut FirstPersonView,
+            &mut ThirdPersonCamera,
+        ),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    for (device, active_mode, mut first_person, mut third_person) in &mut cameras {
+        let PlayerInputDevice::Gamepad(gamepad) = *device else {
+            continue;
+        };
+        let Ok(gamepad) = gamepads.get(gamepad) else {
+            continue;
+        };
+        let stick = gamepad.right_stick();
+        if stick.length() < STICK_DEAD_ZONE {
+            continue;
+        }
+
+        // Same convention as mouse motion: positive y looks down
+        let delta = Vec2::new(stick.x, -stick.y) * STICK_LOOK_SPEED * time.delta_secs();
+        match active_mode.mode {
+            CameraMode::FirstPerson => {
+                let invert = if first_person.invert_y { -1.0 } else { 1.0 };
+                first_person.turn(delta * Vec2::new(1.0, invert));
+            }
+            CameraMode::ThirdPerson => {
+                let invert = if third_person.invert_y { -1.0 } else { 1.0 };
+                third_person.turn(delta * Vec2::new(1.0, invert));
+            }
+            CameraMode::PanOrbit | CameraMode::Isometric | CameraMode::FreeFly => {}
+        }
+    }
+}
+
+pub(super) fn reset_local_players(mut local_players: ResMut<LocalPlayers>) {
+    *local_players = LocalPlayers::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gamepads(count: usize) -> Vec<PlayerInputDevice> {
+        let mut world = World::new();
+        (0..count)
+            .map(|_| PlayerInputDevice::Gamepad(world.spawn_empty().id()))
+            .collect()
+    }
+
+    #[test]
+    fn viewports_cover_the_window_without_overlapping() {
+        for count in 1..=MAX_LOCAL_PLAYERS {
+            for wide in [true, false] {
+                let areas: Vec<_> = (0..count)
+                    .map(|slot| viewport_area(LocalPlayerSlot(slot), count, wide))
+                    .collect();
+                let total: f32 = areas.iter().map(|area| area.width() * area.height()).sum();
+                assert!((total - 1.0).abs() < 1e-6, "{count} players leave gaps");
+                for (index, area) in areas.iter().enumerate() {
+                    assert!(Rect::new(0.0, 0.0, 1.0, 1.0).contains(area.min));
+                    assert!(Rect::new(0.0, 0.0, 1.0, 1.0).contains(area.max));
+                    for other in &areas[index + 1..] {
+                        assert!(area.intersect(*other).is_empty(), "{count} players overlap");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn two_players_split_along_the_longer_side() {
+        let wide = viewport_area(LocalPlayerSlot(1), 2, true);
+        assert_eq!(wide, Rect::new(0.5, 0.0, 1.0, 1.0));
+        let tall = viewport_area(LocalPlayerSlot(1), 2, false);
+        assert_eq!(tall, Rect::new(0.0, 0.5, 1.0, 1.0));
+    }
+
+    #[test]
+    fn primary_player_gets_the_top_half_of_three() {
+        assert_eq!(
+            viewport_area(LocalPlayerSlot::PRIMARY, 3, true),
+            Rect::new(0.0, 0.0, 1.0, 0.5)
+        );
+    }
+
+    #[test]
+    fn gamepads_join_behind_the_keyboard_player() {
+        let pads = gamepads(4);
+        let mut players = LocalPlayers::default();
+        assert!(!players.is_split());
+        assert_eq!(
+            players.slot_of(PlayerInputDevice::KeyboardMouse),
+            Some(LocalPlayerSlot::PRIMARY)
+        );
+
+        assert_eq!(players.join(pads[0]), Some(LocalPlayerSlot(1)));
+        assert!(players.is_split());
+        // Joining twice keeps the slot
+        assert_eq!(players.join(pads[0]), None);
+        assert_eq!(players.join(PlayerInputDevice::KeyboardMouse), None);
+
+        assert_eq!(players.join(pads[1]), Some(LocalPlayerSlot(2)));
+        assert_eq!(players.join(pads[2]), Some(LocalPlayerSlot(3)));
+        // Full
+        assert_eq!(players.join(pads[3]), None);
+        assert_eq!(players.count(), MAX_LOCAL_PLAYERS);
+    }
+
+    #[test]
+    fn players_behind_a_leaving_one_move_up() {
+        let pads = gamepads(2);
+        let mut players = LocalPlayers::default();
+        players.join(pads[0]);
+        players.join(pads[1]);
+
+        assert!(!players.leave(LocalPlayerSlot::PRIMARY));
+        assert!(!players.leave(LocalPlayerSlot(5)));
+        assert!(players.leave(LocalPlayerSlot(1)));
+        assert_eq!(players.slot_of(pads[0]), None);
+        assert_eq!(players.slot_of(pads[1]), Some(LocalPlayerSlot(1)));
+
+        assert!(players.leave(LocalPlayerSlot(1)));
+        assert!(!players.is_split());
+    }
+}
@@ -0,0 +1,297 @@
+// scenes/in_game/cameras/third_person.rs
+//
+// Third-person camera following the LocalPlayer on a spring arm (see ReadCamera.md).
+
+use super::{
+    ActiveCameraMode, CameraInputUnlocked, CameraMode, InGameCamera, LocalPlayerSlot,
+    PlayerInputDevice,
+};
+use crate::networking::LocalPlayer;
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Scroll lines per unit of arm length
+const ZOOM_PER_LINE: f32 = 0.5;
+const ZOOM_PER_PIXEL: f32 = 0.01;
+
+#[derive(Component)]
+pub struct ThirdPersonCamera {
+    /// Rotation around the player (radians, 0 = looking along -Z)
+    pub yaw: f32,
+    /// Vertical angle, positive looks down onto the player (radians)
+    pub pitch: f32,
+    pub sensitivity: f32,
+    /// Moving the mouse up looks down
+    pub invert_y: bool,
+    /// Desired arm length
+    pub distance: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    pub min_pitch: f32,
+    pub max_pitch: f32,
+    /// Pitch the camera returns to after inactivity
+    pub default_pitch: f32,
+    /// Height of the pivot above the player origin
+    pub pivot_height: f32,
+    /// Follow smoothing of the pivot (2 = soft, 10 = responsive)
+    pub lag_speed: f32,
+    /// Spring constant of the arm (critically damped)
+    pub spring_stiffness: f32,
+    /// Radius kept free between camera and geometry
+    pub collision_radius: f32,
+    /// Screen position of the player in normalized coordinates (-1..1), (0, -0.3) = bottom third
+    pub framing_offset: Vec2,
+    /// Seconds without mouse input before the camera swings back behind the player
+    pub auto_reset_delay: f32,
+    /// Speed of the auto reset (1/s)
+    pub auto_reset_speed: f32,
+
+    // Runtime state
+    /// Current (collision-adjusted) arm length
+    pub current_distance: f32,
+    pub arm_velocity: f32,
+    pub pivot: Option<Vec3>,
+    pub idle_time: f32,
+}
+
+impl Default for ThirdPersonCamera {
+    fn default() -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 20f32.to_radians(),
+            sensitivity: 0.002,
+            invert_y: false,
+            distance: 5.0,
+            min_distance: 3.0,
+            max_distance: 8.0,
+            min_pitch: -45f32.to_radians(),
+            max_pitch: 60f32.to_radians(),
+            default_pitch: 20f32.to_radians(),
+            pivot_height: 1.5,
+            lag_speed: 6.0,
+            spring_stiffness: 120.0,
+            collision_radius: 0.3,
+            framing_offset: Vec2::new(0.0, -0.3),
+            auto_reset_delay: 3.0,
+            auto_reset_speed: 2.0,
+            current_distance: 5.0,
+            arm_velocity: 0.0,
+            pivot: None,
+            idle_time: 0.0,
+        }
+    }
+}
+
+impl ThirdPersonCamera {
+    /// Camera rotation for the current yaw/pitch
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw) * Quat::from_rotation_x(-self.pitch)
+    }
+
+    /// Re-enters the mode looking along the given view yaw/pitch (pitch positive looks up),
+    /// keeping the arm length from the last time it was active.
+    pub fn look_along(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = yaw.rem_euclid(TAU);
+        self.pitch = (-pitch).clamp(self.min_pitch, self.max_pitch);
+        self.current_distance = self.distance;
+        self.arm_velocity = 0.0;
+        self.pivot = None;
+        self.idle_time = 0.0;
+    }
+
+    /// Turns the arm by a mouse-style delta (radians, positive y looks down)
+    pub fn turn(&mut self, delta: Vec2) {
+        self.yaw = (self.yaw - delta.x).rem_euclid(TAU);
+        self.pitch = (self.pitch + delta.y).clamp(self.min_pitch, self.max_pitch);
+        self.idle_time = 0.0;
+    }
+}
+
+/// Mouse look and zoom for the keyboard & mouse player
+pub fn handle_mouse_look(
+    mut mouse_motion: MessageReader<MouseMotion>,
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    mut camera: Query<
+        (
+            &mut ThirdPersonCamera,
+            &ActiveCameraMode,
+            &PlayerInputDevice,
+        ),
+        (With<InGameCamera>, CameraInputUnlocked),
+    >,
+) {
+    let Some(mut view) = camera
+        .iter_mut()
+        .find(|(_, active_mode, device)| {
+            active_mode.mode == CameraMode::ThirdPerson
+                && **device == PlayerInputDevice::KeyboardMouse
+        })
+        .map(|(view, _, _)| view)
+    else {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        return;
+    };
+
+    for motion in mouse_motion.read() {
+        if motion.delta == Vec2::ZERO {
+            continue;
+        }
+        let mut delta = motion.delta * view.sensitivity;
+        if view.invert_y {
+            delta.y = -delta.y;
+        }
+        view.turn(delta);
+    }
+
+    for wheel in mouse_wheel.read() {
+        let amount = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y * ZOOM_PER_LINE,
+            MouseScrollUnit::Pixel => wheel.y * ZOOM_PER_PIXEL,
+        };
+        view.distance = (view.distance - amount).clamp(view.min_distance, view.max_distance);
+    }
+}
+
+/// Swings the camera back behind the player after `auto_reset_delay` seconds without input.
+pub fn auto_reset(
+    time: Res<Time>,
+    local_players: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
+    mut cameras: Query<
+        (&mut ThirdPersonCamera, &ActiveCameraMode, &LocalPlayerSlot),
+        With<InGameCamera>,
+    >,
+) {
+    for (mut view, active_mode, slot) in &mut cameras {
+        if active_mode.mode != CameraMode::ThirdPerson {
+            continue;
+        }
+        let Some(player) = slot.find(local_players.iter()) else {
+            continue;
+        };
+
+        view.idle_time += time.delta_secs();
+        if view.idle_time < view.auto_reset_delay {
+            continue;
+        }
+
+        let (player_yaw, _, _) = player.rotation.to_euler(EulerRot::YXZ);
+        let t = 1.0 - (-view.auto_reset_speed * time.delta_secs()).exp();
+        // Shortest way around the circle
+        let yaw_delta = (player_yaw - view.yaw + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+        let pitch_delta = view.default_pitch - view.pitch;
+        view.yaw = (view.yaw + yaw_delta * t).rem_euclid(TAU);
+        view.pitch += pitch_delta * t;
+    }
+}
+
+/// Moves the camera on its spring arm behind the player and pulls it in on collisions.
+pub fn follow_player(
+    time: Res<Time>,
+    mut ray_cast: MeshRayCast,
+    local_players: Query<
+        (Entity, &Transform, &LocalPlayerSlot),
+        (With<LocalPlayer>, Without<InGameCamera>),
+    >,
+    parents: Query<&ChildOf>,
+    mut cameras: Query<
+        (
+            &mut ThirdPersonCamera,
+            &mut Transform,
+            &Projection,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+        ),
+        With<InGameCamera>,
+    >,
+) {
+    let dt = time.delta_secs();
+
+    for (mut view, mut transform, projection, active_mode, slot) in &mut cameras {
+        if active_mode.mode != CameraMode::ThirdPerson {
+            continue;
+        }
+        let Some((player_entity, player)) = slot.find(
+            local_players
+                .iter()
+                .map(|(entity, transform, slot)| ((entity, transform), slot)),
+        ) else {
+            continue;
+        };
+
+        // Lagged pivot
+        let target_pivot = player.translation + Vec3::Y * view.pivot_height;
+        let pivot = match view.pivot {
+            Some(pivot) => pivot.lerp(target_pivot, 1.0 - (-view.lag_speed * dt).exp()),
+            None => target_pivot,
+        };
+        view.pivot = Some(pivot);
+
+        let rotation = view.rotation();
+        let back = rotation * Vec3::Z;
+
+        // Collision: shorten the arm to stay `collision_radius` in front of the first hit
+        // Ignore the player and anything attached to it
+        let filter = |entity: Entity| {
+            entity != player_entity
+                && parents
+                    .get(entity)
+                    .map_or(true, |child_of| child_of.parent() != player_entity)
+        };
+        let settings = MeshRayCastSettings::default().with_filter(&filter);
+        let blocked_distance = Dir3::new(back)
+            .ok()
+            .and_then(|direction| {
+                ray_cast
+                    .cast_ray(Ray3d::new(pivot, direction), &settings)
+                    .first()
+                    .map(|(_, hit)| hit.distance)
+            })
+            .filter(|distance| *distance < view.distance + view.collision_radius)
+            .map(|distance| (distance - view.collision_radius).max(0.1));
+
+        match blocked_distance {
+            // Pull in immediately so geometry never ends up between camera and player
+            Some(distance) if distance < view.current_distance => {
+                view.current_distance = distance;
+                view.arm_velocity = 0.0;
+            }
+            _ => {
+                // Critically damped spring back out to the desired length
+                let target = blocked_distance.unwrap_or(view.distance);
+                let omega = view.spring_stiffness.sqrt();
+                let displacement = view.current_distance - target;
+                let acceleration = -omega * omega * displacement - 2.0 * omega * view.arm_velocity;
+                view.arm_velocity += acceleration * dt;
+                view.current_distance += view.arm_velocity * dt;
+            }
+        }
+
+        // Framing: shift the camera so the pivot lands at `framing_offset` on screen
+        let half_height = match projection {
+            Projection::Perspective(perspective) => {
+                view.current_distance * (perspective.fov / 2.0).tan()
+            }
+            _ => 0.0,
+        };
+        let half_width = half_height
+            * match projection {
+                Projection::Perspective(perspective) => perspective.aspect_ratio,
+                _ => 1.0,
+            };
+        let framing = rotation
+            * Vec3::new(
+                -view.framing_offset.x * half_width,
+                -view.framing_offset.y * half_height,
+                0.0,
+            );
+
+        transform.translation = pivot + back * view.current_distance + framing;
+        transform.rotation = rotation;
+    }
+}
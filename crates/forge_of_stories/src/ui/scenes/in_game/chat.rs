@@ -0,0 +1,181 @@
+//! In-game chat HUD
+//!
+//! Shows the most recent chat lines in the bottom-left corner. Lines fade out after a while
+//! and are shown again at full opacity while the chat input line is open.
+
+use crate::chat::{ChatLog, ChatSender};
+use crate::ui::components::ChatInputState;
+use crate::{GameState, utils::cleanup};
+use bevy::prelude::*;
+
+/// Number of history lines shown at once.
+const VISIBLE_LINES: usize = 10;
+/// Seconds a line stays fully visible before fading.
+const FADE_DELAY_SECS: f32 = 8.0;
+/// Seconds a line takes to fade out.
+const FADE_DURATION_SECS: f32 = 2.0;
+
+const SYSTEM_COLOR: Color = Color::srgb(0.95, 0.85, 0.4);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+
+pub(super) struct ChatHudPlugin;
+
+impl Plugin for ChatHudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_chat_hud)
+            .add_systems(
+                Update,
+                (
+                    rebuild_chat_history,
+                    fade_chat_history,
+                    update_chat_input_line,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(OnExit(GameState::InGame), cleanup::<ChatHud>);
+    }
+}
+
+/// Marker component for the chat HUD root
+#[derive(Component)]
+pub(super) struct ChatHud;
+
+/// Container for the history lines
+#[derive(Component)]
+struct ChatHistory;
+
+/// A rendered history line, remembering when it was received for fading
+#[derive(Component)]
+struct ChatLine {
+    received_at: f32,
+    color: Color,
+}
+
+/// The input line shown while typing
+#[derive(Component)]
+struct ChatInputLine;
+
+fn spawn_chat_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(420.0),
+                row_gap: Val::Px(4.0),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ChatHud,
+            Name::new("Chat HUD"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    row_gap: Val::Px(2.0),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ChatHistory,
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(4.0)),
+                    display: Display::None,
+                    ..default()
+                },
+                BorderRadius::all(Val::Px(4.0)),
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                ChatInputLine,
+            ));
+        });
+}
+
+/// Respawns the history lines whenever the chat log changes.
+fn rebuild_chat_history(
+    mut commands: Commands,
+    log: Res<ChatLog>,
+    history: Query<Entity, With<ChatHistory>>,
+) {
+    if !log.is_changed() {
+        return;
+    }
+
+    let Ok(history) = history.single() else {
+        return;
+    };
+
+    commands.entity(history).despawn_children();
+
+    let entries: Vec<_> = log.entries().rev().take(VISIBLE_LINES).collect();
+    commands.entity(history).with_children(|parent| {
+        for entry in entries.into_iter().rev() {
+            let (text, color) = match &entry.sender {
+                ChatSender::System => (entry.text.clone(), SYSTEM_COLOR),
+                ChatSender::Player { name, color } => (format!("{}: {}", name, entry.text), *color),
+            };
+
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(color),
+                TextShadow::default(),
+                ChatLine {
+                    received_at: entry.received_at,
+                    color,
+                },
+            ));
+        }
+    });
+}
+
+fn fade_chat_history(
+    time: Res<Time>,
+    chat: Res<ChatInputState>,
+    mut lines: Query<(&ChatLine, &mut TextColor)>,
+) {
+    let now = time.elapsed_secs();
+
+    for (line, mut text_color) in &mut lines {
+        let alpha = if chat.is_open() {
+            1.0
+        } else {
+            let age = now - line.received_at;
+            (1.0 - (age - FADE_DELAY_SECS) / FADE_DURATION_SECS).clamp(0.0, 1.0)
+        };
+        text_color.0 = line.color.with_alpha(alpha);
+    }
+}
+
+fn update_chat_input_line(
+    chat: Res<ChatInputState>,
+    mut input_line: Query<(&mut Text, &mut Node), With<ChatInputLine>>,
+) {
+    if !chat.is_changed() {
+        return;
+    }
+
+    let Ok((mut text, mut node)) = input_line.single_mut() else {
+        return;
+    };
+
+    if chat.is_open() {
+        node.display = Display::Flex;
+        text.0 = format!("> {}_", chat.buffer);
+    } else {
+        node.display = Display::None;
+        text.0.clear();
+    }
+}
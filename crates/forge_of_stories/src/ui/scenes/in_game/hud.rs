@@ -1,5 +1,9 @@
 //! In-game HUD (Heads-Up Display)
 
+use crate::ui::debug_overlay::DebugOverlayAppExt;
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, InGameCamera, LocalPlayerSlot, LocalPlayers, PlayerInputDevice,
+};
 use crate::{GameState, utils::cleanup};
 use bevy::prelude::*;
 use game_server::ServerHandle;
@@ -8,7 +12,14 @@ pub(super) struct InGameHUDPlugin;
 
 impl Plugin for InGameHUDPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::InGame), spawn_hud)
+        app.register_debug_marker::<InGameHUD>("InGameHUD")
+            .add_systems(OnEnter(GameState::InGame), spawn_hud)
+            .add_systems(
+                Update,
+                (spawn_player_huds, update_player_huds)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            )
             .add_systems(OnExit(GameState::InGame), cleanup::<InGameHUD>);
     }
 }
@@ -17,10 +28,20 @@ impl Plugin for InGameHUDPlugin {
 #[derive(Component)]
 pub(super) struct InGameHUD;
 
-fn spawn_hud(mut commands: Commands, server: Res<ServerHandle>) {
+/// Per-player HUD, rendered into the viewport of the player's camera
+#[derive(Component)]
+struct PlayerHUD {
+    camera: Entity,
+}
+
+fn spawn_hud(mut commands: Commands, server: Option<Res<ServerHandle>>) {
+    let session = match server {
+        Some(server) => format!("Singleplayer (port {})", server.port()),
+        None => "Multiplayer".to_string(),
+    };
     let ui_text = format!(
-        "Singleplayer\nPress ESC for menu\nPress C to toggle camera\nServer Port: {}",
-        server.port()
+        "{}\nPress ESC for menu\nPress C to toggle camera\nPress V for free-fly camera (T: back to player)\nPress Start on a gamepad to join (Select: leave)\nPress Enter to chat\nPress M for map\nHold Tab for player list\nPress N for notifications\nPress ` for console\nPress F3 for debug overlay\nPress F12 for a screenshot",
+        session
     );
 
     commands.spawn((
@@ -40,3 +61,58 @@ fn spawn_hud(mut commands: Commands, server: Res<ServerHandle>) {
         Name::new("InGame HUD"),
     ));
 }
+
+fn spawn_player_huds(mut commands: Commands, cameras: Query<Entity, Added<InGameCamera>>) {
+    for camera in &cameras {
+        commands.spawn((
+            Text::default(),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                ..default()
+            },
+            UiTargetCamera(camera),
+            Visibility::Hidden,
+            PlayerHUD { camera },
+            InGameHUD,
+            Name::new("Player HUD"),
+        ));
+    }
+}
+
+/// Labels each viewport with its player while the screen is split, drops the HUDs of players
+/// that left.
+fn update_player_huds(
+    mut commands: Commands,
+    local_players: Res<LocalPlayers>,
+    cameras: Query<(&LocalPlayerSlot, &PlayerInputDevice, &ActiveCameraMode), With<InGameCamera>>,
+    mut huds: Query<(Entity, &PlayerHUD, &mut Text, &mut Visibility)>,
+) {
+    for (entity, hud, mut text, mut visibility) in &mut huds {
+        let Ok((slot, device, active_mode)) = cameras.get(hud.camera) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let label = format!(
+            "Player {} · {} · {:?}",
+            slot.0 + 1,
+            device.label(),
+            active_mode.mode
+        );
+        if text.0 != label {
+            text.0 = label;
+        }
+        visibility.set_if_neq(if local_players.is_split() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
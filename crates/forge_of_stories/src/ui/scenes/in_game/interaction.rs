@@ -0,0 +1,330 @@
+//! Crosshair and contextual interaction prompt
+//!
+//! In first person a crosshair is shown in the centre of the viewport and a ray is cast every
+//! frame from the `InGameCamera` along its view direction; with a split screen every player
+//! camera has its own. If the closest hit (or one of its ancestors) is [`Interactable`] and
+//! within reach, a "Press E to …" prompt is shown with the currently bound key or gamepad
+//! button. Pressing it triggers an [`Interact`] event for the entity.
+
+use super::cameras::{
+    ActiveCameraMode, CameraMode, CursorState, InGameCamera, LocalPlayerSlot, PlayerInputDevice,
+};
+use super::hud::InGameHUD;
+use crate::GameState;
+use crate::networking::LocalPlayer;
+use crate::ui::components::{ChatInputState, ConsoleState, InGameMenuState, PhotoModeState};
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::prelude::*;
+
+const CROSSHAIR_SIZE: f32 = 4.0;
+
+pub(super) struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InteractionSettings>().add_systems(
+            Update,
+            (
+                spawn_crosshairs,
+                update_interaction_target,
+                update_crosshair_ui,
+                handle_interact_key,
+            )
+                .chain()
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+/// Marks an entity the player can interact with in first person.
+#[derive(Component, Debug, Clone)]
+pub struct Interactable {
+    /// Verb shown in the prompt, e.g. "open" → "Press E to open"
+    pub verb: String,
+    /// Maximum distance from the camera in metres
+    pub range: f32,
+}
+
+impl Interactable {
+    pub fn new(verb: impl Into<String>) -> Self {
+        Self {
+            verb: verb.into(),
+            range: 3.0,
+        }
+    }
+
+    pub fn with_range(mut self, range: f32) -> Self {
+        self.range = range;
+        self
+    }
+}
+
+/// Event triggered when a player presses the interact key or button while looking at an
+/// [`Interactable`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Interact {
+    pub entity: Entity,
+    /// The local player interacting
+    pub slot: LocalPlayerSlot,
+}
+
+/// Interaction bindings and ray length
+#[derive(Resource, Debug, Clone)]
+pub struct InteractionSettings {
+    pub key: KeyCode,
+    /// Button of gamepad players
+    pub button: GamepadButton,
+    /// Length of the look ray; individual ranges are limited by [`Interactable::range`]
+    pub max_distance: f32,
+}
+
+impl Default for InteractionSettings {
+    fn default() -> Self {
+        Self {
+            key: KeyCode::KeyE,
+            button: GamepadButton::West,
+            max_distance: 10.0,
+        }
+    }
+}
+
+/// Interactable entity currently under the crosshair of a player camera
+#[derive(Component, Default, Debug)]
+pub struct InteractionTarget(pub Option<Entity>);
+
+/// Crosshair dot in the centre of a player camera's viewport
+#[derive(Component)]
+struct Crosshair {
+    camera: Entity,
+}
+
+/// "Press E to …" prompt below the crosshair
+#[derive(Component)]
+struct InteractionPrompt {
+    camera: Entity,
+}
+
+/// Root of the crosshair and prompt of a player camera
+#[derive(Component)]
+struct CrosshairRoot {
+    camera: Entity,
+}
+
+/// Spawns crosshair and prompt for every new player camera, despawns those of removed ones.
+fn spawn_crosshairs(
+    mut commands: Commands,
+    added: Query<Entity, Added<InGameCamera>>,
+    cameras: Query<(), With<InGameCamera>>,
+    roots: Query<(Entity, &CrosshairRoot)>,
+) {
+    for (entity, root) in &roots {
+        if !cameras.contains(root.camera) {
+            commands.entity(entity).despawn();
+        }
+    }
+    for camera in &added {
+        commands.entity(camera).insert(InteractionTarget::default());
+        spawn_crosshair(&mut commands, camera);
+    }
+}
+
+fn spawn_crosshair(commands: &mut Commands, camera: Entity) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+            UiTargetCamera(camera),
+            CrosshairRoot { camera },
+            InGameHUD,
+            Name::new("Crosshair"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(CROSSHAIR_SIZE),
+                    height: Val::Px(CROSSHAIR_SIZE),
+                    ..default()
+                },
+                BorderRadius::MAX,
+                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+                Outline::new(Val::Px(1.0), Val::ZERO, Color::srgba(0.0, 0.0, 0.0, 0.6)),
+                Crosshair { camera },
+            ));
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                TextShadow::default(),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Percent(54.0),
+                    ..default()
+                },
+                Visibility::Hidden,
+                InteractionPrompt { camera },
+            ));
+        });
+}
+
+/// Casts the look ray from the centre of every first person camera and stores the
+/// interactable under its crosshair.
+#[allow(clippy::type_complexity)]
+fn update_interaction_target(
+    mut ray_cast: MeshRayCast,
+    settings: Res<InteractionSettings>,
+    mut cameras: Query<
+        (
+            &GlobalTransform,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+            &mut InteractionTarget,
+        ),
+        With<InGameCamera>,
+    >,
+    local_players: Query<(Entity, &LocalPlayerSlot), With<LocalPlayer>>,
+    interactables: Query<&Interactable>,
+    parents: Query<&ChildOf>,
+) {
+    for (camera_transform, active_mode, slot, mut target) in &mut cameras {
+        let found = (active_mode.mode == CameraMode::FirstPerson)
+            .then(|| {
+                // The ray starts inside the camera's own player
+                let player = slot.find(local_players.iter());
+                let filter = |entity: Entity| Some(entity) != player;
+                let settings_ray = MeshRayCastSettings::default().with_filter(&filter);
+                let origin = camera_transform.translation();
+                let ray = Ray3d::new(origin, camera_transform.forward());
+
+                let (hit_entity, hit) = ray_cast.cast_ray(ray, &settings_ray).first()?.clone();
+                if hit.distance > settings.max_distance {
+                    return None;
+                }
+
+                // The hit mesh may be a child of the interactable entity
+                std::iter::once(hit_entity)
+                    .chain(parents.iter_ancestors::<ChildOf>(hit_entity))
+                    .find(|entity| {
+                        interactables
+                            .get(*entity)
+                            .is_ok_and(|interactable| hit.distance <= interactable.range)
+                    })
+            })
+            .flatten();
+
+        if target.0 != found {
+            target.0 = found;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_crosshair_ui(
+    settings: Res<InteractionSettings>,
+    cameras: Query<(&ActiveCameraMode, &PlayerInputDevice, &InteractionTarget), With<InGameCamera>>,
+    interactables: Query<&Interactable>,
+    mut crosshairs: Query<(&Crosshair, &mut Visibility), Without<InteractionPrompt>>,
+    mut prompts: Query<(&InteractionPrompt, &mut Text, &mut Visibility), Without<Crosshair>>,
+) {
+    let first_person = |camera: Entity| {
+        cameras
+            .get(camera)
+            .is_ok_and(|(active_mode, ..)| active_mode.mode == CameraMode::FirstPerson)
+    };
+
+    for (crosshair, mut visibility) in &mut crosshairs {
+        visibility.set_if_neq(if first_person(crosshair.camera) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+
+    for (prompt, mut text, mut visibility) in &mut prompts {
+        let Ok((active_mode, device, target)) = cameras.get(prompt.camera) else {
+            continue;
+        };
+        match target.0.and_then(|entity| interactables.get(entity).ok()) {
+            Some(interactable) if active_mode.mode == CameraMode::FirstPerson => {
+                let binding = match device {
+                    PlayerInputDevice::KeyboardMouse => key_label(settings.key),
+                    PlayerInputDevice::Gamepad(_) => button_label(settings.button),
+                };
+                let label = format!("Press {} to {}", binding, interactable.verb);
+                if text.0 != label {
+                    text.0 = label;
+                }
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            _ => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+/// The keyboard player interacts with the key while the cursor is locked, gamepad players with
+/// their button.
+#[allow(clippy::too_many_arguments)]
+fn handle_interact_key(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<InteractionSettings>,
+    cursor: Res<CursorState>,
+    menu: Res<InGameMenuState>,
+    chat: Res<ChatInputState>,
+    console: Res<ConsoleState>,
+    photo: Res<PhotoModeState>,
+    gamepads: Query<&Gamepad>,
+    cameras: Query<(&LocalPlayerSlot, &PlayerInputDevice, &InteractionTarget), With<InGameCamera>>,
+) {
+    if menu.is_open() || chat.is_open() || console.is_open() || photo.is_active() {
+        return;
+    }
+
+    for (slot, device, target) in &cameras {
+        let pressed = match device {
+            PlayerInputDevice::KeyboardMouse => {
+                *cursor == CursorState::LOCKED && keyboard.just_pressed(settings.key)
+            }
+            PlayerInputDevice::Gamepad(gamepad) => gamepads
+                .get(*gamepad)
+                .is_ok_and(|gamepad| gamepad.just_pressed(settings.button)),
+        };
+        if let (true, Some(entity)) = (pressed, target.0) {
+            commands.trigger(Interact {
+                entity,
+                slot: *slot,
+            });
+        }
+    }
+}
+
+/// Face button names as printed on Xbox-style pads (`West` → `X`).
+fn button_label(button: GamepadButton) -> String {
+    match button {
+        GamepadButton::South => "A".to_string(),
+        GamepadButton::East => "B".to_string(),
+        GamepadButton::West => "X".to_string(),
+        GamepadButton::North => "Y".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// Human readable name of a key (`KeyE` → `E`, `Digit1` → `1`).
+fn key_label(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    name.strip_prefix("Key")
+        .or_else(|| name.strip_prefix("Digit"))
+        .map(str::to_string)
+        .unwrap_or(name)
+}
@@ -0,0 +1,502 @@
+//! Minimap HUD widget and full-screen map view
+//!
+//! A top-down orthographic camera per player camera renders the world around its player into a
+//! texture, shown in the bottom-right corner of that player's viewport. The full-screen map has
+//! its own map camera, only rendering while the map is open. Player markers are flat discs in
+//! the player's colour on a dedicated render layer, so only the map cameras see them.
+//!
+//! - **M** - open/close the full-screen map (scroll to zoom, drag to pan, ESC to close)
+//! - **Shift+M** - toggle minimap rotation with the camera yaw
+
+use crate::networking::LocalPlayer;
+use crate::ui::components::{
+    ChatInputState, ConsoleState, HOVERED_BUTTON, InGameMenuState, MapViewState, NORMAL_BUTTON,
+    PhotoModeState,
+};
+use crate::ui::icons::Icons;
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CursorState, InGameCamera, LocalPlayerSlot, PlayerInputDevice,
+};
+use crate::{GameState, utils::cleanup};
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{RenderTarget, ScalingMode};
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use game_server::Player;
+
+/// Render layer only seen by the map camera.
+const MAP_LAYER: usize = 1;
+/// Resolution of the full-screen map render target.
+const MAP_TEXTURE_SIZE: u32 = 1024;
+/// Resolution of the minimap render targets.
+const MINIMAP_TEXTURE_SIZE: u32 = 512;
+/// Size of the minimap widget in logical pixels.
+const MINIMAP_SIZE: f32 = 200.0;
+/// Height of the map camera above the followed position.
+const MAP_CAMERA_HEIGHT: f32 = 100.0;
+/// Zoom limits of the full-screen map (visible world units vertically).
+const MAP_MIN_EXTENT: f32 = 10.0;
+const MAP_MAX_EXTENT: f32 = 500.0;
+
+pub(super) struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MinimapSettings>()
+            .init_resource::<MapViewState>()
+            .add_systems(OnEnter(GameState::InGame), setup_minimap)
+            .add_systems(
+                Update,
+                (
+                    handle_map_toggle,
+                    handle_map_button,
+                    (handle_map_zoom, handle_map_pan).run_if(map_view_open),
+                    sync_minimaps,
+                    spawn_player_markers,
+                    update_map_view_ui,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                PostUpdate,
+                update_map_camera
+                    .run_if(in_state(GameState::InGame))
+                    .before(bevy::transform::TransformSystems::Propagate),
+            )
+            .add_systems(
+                OnExit(GameState::InGame),
+                (cleanup::<MinimapUI>, cleanup::<MapCamera>, close_map_view),
+            );
+    }
+}
+
+/// Minimap configuration
+#[derive(Resource, Debug)]
+pub struct MinimapSettings {
+    /// Rotate the minimap so that the camera's viewing direction points up.
+    pub rotate_with_camera: bool,
+    /// World units visible vertically on the minimap.
+    pub minimap_extent: f32,
+    /// World units visible vertically on the full-screen map.
+    pub map_extent: f32,
+    /// Centre of the full-screen map on the XZ plane.
+    pub map_center: Vec2,
+}
+
+impl Default for MinimapSettings {
+    fn default() -> Self {
+        Self {
+            rotate_with_camera: false,
+            minimap_extent: 40.0,
+            map_extent: 120.0,
+            map_center: Vec2::ZERO,
+        }
+    }
+}
+
+/// Top-down map camera, following the player of `camera` for its minimap. Without a camera it
+/// renders the full-screen map.
+#[derive(Component)]
+struct MapCamera {
+    camera: Option<Entity>,
+}
+
+/// Marker for minimap and map view UI entities
+#[derive(Component)]
+pub(super) struct MinimapUI;
+
+/// Minimap widget in the viewport of a player camera
+#[derive(Component)]
+struct Minimap {
+    camera: Entity,
+}
+
+/// The full-screen map overlay
+#[derive(Component)]
+struct MapViewOverlay;
+
+/// Button opening the full-screen map
+#[derive(Component)]
+struct MapButton;
+
+/// Marks players that already have a map marker
+#[derive(Component)]
+struct HasMapMarker;
+
+fn map_view_open(map: Res<MapViewState>) -> bool {
+    map.is_open()
+}
+
+/// Spawns a map camera rendering into a new texture of `size` pixels, returns the texture.
+fn spawn_map_camera(
+    commands: &mut Commands,
+    images: &mut Assets<Image>,
+    camera: Option<Entity>,
+    size: u32,
+) -> Handle<Image> {
+    let mut image = Image::new_target_texture(size, size, TextureFormat::Bgra8UnormSrgb);
+    image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+    let image = images.add(image);
+
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            order: -1,
+            target: RenderTarget::Image(image.clone().into()),
+            clear_color: ClearColorConfig::Custom(Color::srgb(0.05, 0.07, 0.1)),
+            // The full-screen map renders once opened
+            is_active: camera.is_some(),
+            ..default()
+        },
+        Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: MinimapSettings::default().minimap_extent,
+            },
+            far: MAP_CAMERA_HEIGHT * 2.0,
+            ..OrthographicProjection::default_3d()
+        }),
+        Transform::from_xyz(0.0, MAP_CAMERA_HEIGHT, 0.0).looking_at(Vec3::ZERO, Vec3::NEG_Z),
+        RenderLayers::from_layers(&[0, MAP_LAYER]),
+        MapCamera { camera },
+        Name::new("Map Camera"),
+    ));
+
+    image
+}
+
+fn setup_minimap(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let image = spawn_map_camera(&mut commands, &mut images, None, MAP_TEXTURE_SIZE);
+
+    // Full-screen map view (hidden until opened)
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            GlobalZIndex(10),
+            MinimapUI,
+            MapViewOverlay,
+            Name::new("Map View"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                ImageNode::new(image),
+                Node {
+                    height: Val::Percent(90.0),
+                    aspect_ratio: Some(1.0),
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Spawns map camera and minimap widget for every new player camera, despawns those of
+/// removed ones.
+fn sync_minimaps(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    icons: Res<Icons>,
+    added: Query<(Entity, &PlayerInputDevice), Added<InGameCamera>>,
+    cameras: Query<(), With<InGameCamera>>,
+    map_cameras: Query<(Entity, &MapCamera)>,
+    minimaps: Query<(Entity, &Minimap)>,
+) {
+    let orphaned = map_cameras
+        .iter()
+        .filter_map(|(entity, map_camera)| Some((entity, map_camera.camera?)))
+        .chain(
+            minimaps
+                .iter()
+                .map(|(entity, minimap)| (entity, minimap.camera)),
+        );
+    for (entity, camera) in orphaned {
+        if !cameras.contains(camera) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (camera, device) in &added {
+        let image = spawn_map_camera(
+            &mut commands,
+            &mut images,
+            Some(camera),
+            MINIMAP_TEXTURE_SIZE,
+        );
+        commands
+            .spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(10.0),
+                    bottom: Val::Px(10.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexEnd,
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                UiTargetCamera(camera),
+                Minimap { camera },
+                MinimapUI,
+                Name::new("Minimap"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    ImageNode::new(image),
+                    Node {
+                        width: Val::Px(MINIMAP_SIZE),
+                        height: Val::Px(MINIMAP_SIZE),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BorderColor::all(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                    BorderRadius::all(Val::Px(MINIMAP_SIZE / 2.0)),
+                ));
+
+                // The full-screen map is opened with the mouse
+                if *device != PlayerInputDevice::KeyboardMouse {
+                    return;
+                }
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::all(Val::Px(6.0)),
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(6.0)),
+                        BackgroundColor(NORMAL_BUTTON),
+                        MapButton,
+                        Name::new("Map Button (M)"),
+                    ))
+                    .with_children(|button| {
+                        button.spawn(icons.node("map", 20.0, Color::srgb(0.9, 0.9, 0.9)));
+                    });
+            });
+    }
+}
+
+/// Adds a flat disc in the player's colour above each player, visible only on the map.
+fn spawn_player_markers(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    players: Query<(Entity, &Player, Has<LocalPlayer>), Without<HasMapMarker>>,
+) {
+    for (entity, player, is_local) in &players {
+        let radius = if is_local { 1.2 } else { 0.9 };
+        let marker = commands
+            .spawn((
+                Mesh3d(meshes.add(Circle::new(radius))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: player.color,
+                    unlit: true,
+                    ..default()
+                })),
+                Transform::from_xyz(0.0, 3.0, 0.0)
+                    .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2)),
+                RenderLayers::layer(MAP_LAYER),
+                Name::new("Map Marker"),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(HasMapMarker)
+            .add_child(marker);
+    }
+}
+
+/// Minimap cameras follow their player, the full-screen map camera the map centre. Only the
+/// cameras of what is shown render.
+#[allow(clippy::type_complexity)]
+fn update_map_camera(
+    settings: Res<MinimapSettings>,
+    map: Res<MapViewState>,
+    local_players: Query<(&Transform, &LocalPlayerSlot), (With<LocalPlayer>, Without<MapCamera>)>,
+    in_game_cameras: Query<
+        (&Transform, &LocalPlayerSlot),
+        (With<InGameCamera>, Without<MapCamera>),
+    >,
+    mut map_cameras: Query<(&MapCamera, &mut Camera, &mut Transform, &mut Projection)>,
+) {
+    for (map_camera, mut camera, mut transform, mut projection) in &mut map_cameras {
+        let active = map_camera.camera.is_none() == map.is_open();
+        if camera.is_active != active {
+            camera.is_active = active;
+        }
+
+        let (center, extent, up) = match map_camera.camera {
+            None => (settings.map_center, settings.map_extent, Vec3::NEG_Z),
+            Some(player_camera) => {
+                let Ok((camera_transform, slot)) = in_game_cameras.get(player_camera) else {
+                    continue;
+                };
+                let Some(player) = slot.find(local_players.iter()) else {
+                    continue;
+                };
+                let up = if settings.rotate_with_camera {
+                    let forward = camera_transform.forward();
+                    Vec3::new(forward.x, 0.0, forward.z).normalize_or(Vec3::NEG_Z)
+                } else {
+                    Vec3::NEG_Z
+                };
+                (player.translation.xz(), settings.minimap_extent, up)
+            }
+        };
+
+        *transform = Transform::from_xyz(center.x, MAP_CAMERA_HEIGHT, center.y)
+            .looking_at(Vec3::new(center.x, 0.0, center.y), up);
+
+        if let Projection::Orthographic(ref mut ortho) = *projection {
+            ortho.scaling_mode = ScalingMode::FixedVertical {
+                viewport_height: extent,
+            };
+        }
+    }
+}
+
+/// Handles M (map view) and Shift+M (minimap rotation).
+#[allow(clippy::too_many_arguments)]
+fn handle_map_toggle(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut map: ResMut<MapViewState>,
+    mut settings: ResMut<MinimapSettings>,
+    mut cursor: ResMut<CursorState>,
+    menu: Res<InGameMenuState>,
+    chat: Res<ChatInputState>,
+    console: Res<ConsoleState>,
+    photo: Res<PhotoModeState>,
+    local_players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    cameras: Query<(&ActiveCameraMode, &LocalPlayerSlot), With<InGameCamera>>,
+) {
+    if menu.is_open() || chat.is_open() || console.is_open() || photo.is_active() {
+        return;
+    }
+
+    // ESC closing the map is handled by the menu input
+    if !keyboard.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+
+    if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        settings.rotate_with_camera = !settings.rotate_with_camera;
+        return;
+    }
+
+    map.toggle();
+    if map.is_open() {
+        if let Some(player) = LocalPlayerSlot::PRIMARY.find(local_players.iter()) {
+            settings.map_center = player.translation.xz();
+        }
+    }
+    *cursor = map_cursor_state(&map, &cameras);
+}
+
+fn handle_map_button(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<MapButton>),
+    >,
+    mut map: ResMut<MapViewState>,
+    mut settings: ResMut<MinimapSettings>,
+    mut cursor: ResMut<CursorState>,
+    local_players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    cameras: Query<(&ActiveCameraMode, &LocalPlayerSlot), With<InGameCamera>>,
+) {
+    for (interaction, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if !map.is_open() {
+                    map.toggle();
+                    if let Some(player) = LocalPlayerSlot::PRIMARY.find(local_players.iter()) {
+                        settings.map_center = player.translation.xz();
+                    }
+                    *cursor = map_cursor_state(&map, &cameras);
+                }
+            }
+            Interaction::Hovered => *color = HOVERED_BUTTON.into(),
+            Interaction::None => *color = NORMAL_BUTTON.into(),
+        }
+    }
+}
+
+/// Cursor is free while the map is open, otherwise it follows the camera mode.
+pub(crate) fn map_cursor_state(
+    map: &MapViewState,
+    cameras: &Query<(&ActiveCameraMode, &LocalPlayerSlot), With<InGameCamera>>,
+) -> CursorState {
+    if map.is_open() {
+        return CursorState::FREE;
+    }
+
+    // The cursor follows the camera of the primary (keyboard & mouse) player
+    LocalPlayerSlot::PRIMARY
+        .find(cameras.iter())
+        .map_or(CursorState::FREE, |mode| mode.mode.cursor_state())
+}
+
+fn handle_map_zoom(
+    mut scroll_events: MessageReader<MouseWheel>,
+    mut settings: ResMut<MinimapSettings>,
+) {
+    for event in scroll_events.read() {
+        let amount = match event.unit {
+            MouseScrollUnit::Line => event.y * 0.1,
+            MouseScrollUnit::Pixel => event.y * 0.005,
+        };
+        settings.map_extent =
+            (settings.map_extent * (1.0 - amount)).clamp(MAP_MIN_EXTENT, MAP_MAX_EXTENT);
+    }
+}
+
+fn handle_map_pan(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: MessageReader<MouseMotion>,
+    mut settings: ResMut<MinimapSettings>,
+    overlay: Query<&ComputedNode, With<MapViewOverlay>>,
+) {
+    let delta: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+    if !mouse_input.pressed(MouseButton::Left) || delta == Vec2::ZERO {
+        return;
+    }
+
+    let Ok(overlay) = overlay.single() else {
+        return;
+    };
+
+    // The map image covers 90% of the overlay height
+    let map_pixels = overlay.size().y * overlay.inverse_scale_factor() * 0.9;
+    if map_pixels <= 0.0 {
+        return;
+    }
+
+    // Map is north-up: screen X is world X, screen Y is world Z
+    let world_per_pixel = settings.map_extent / map_pixels;
+    settings.map_center -= delta * world_per_pixel;
+}
+
+fn update_map_view_ui(map: Res<MapViewState>, mut overlay: Query<&mut Node, With<MapViewOverlay>>) {
+    if !map.is_changed() {
+        return;
+    }
+
+    for mut node in &mut overlay {
+        node.display = if map.is_open() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+fn close_map_view(mut map: ResMut<MapViewState>) {
+    map.set_closed();
+}
@@ -0,0 +1,521 @@
+//! Photo mode and screenshots
+//!
+//! Entered from the in-game menu: the virtual clock is paused, the player cameras are switched
+//! off and a free photo camera takes over at the pose of the primary camera. The HUD, chat,
+//! minimap, nameplates and notifications are hidden until photo mode is left again with ESC.
+//!
+//! - **Mouse** - look around, **WASD** - move, **Q/E** - down/up, **Shift** - faster
+//! - **Z/X** - roll, **R** - reset roll, **Mouse wheel** - field of view
+//! - **F** - toggle depth of field, **Up/Down** - focus distance, **Left/Right** - aperture
+//! - **F12** - screenshot (also outside photo mode)
+//!
+//! Screenshots are saved as timestamped PNGs in the [`ScreenshotsDir`], optionally with a JSON
+//! file next to them describing where they were taken. A toast confirms the PNG once it is
+//! written.
+
+use super::chat::ChatHud;
+use super::hud::InGameHUD;
+use super::minimap::MinimapUI;
+use super::world::{Nameplate, NameplateSystems};
+use crate::GameState;
+use crate::app::{LOG_CLIENT, ScreenshotsDir, WorldName};
+use crate::networking::LocalPlayer;
+use crate::ui::components::{
+    InGameMenuState, PhotoModeState, chat_allows_input, console_allows_input,
+};
+use crate::ui::notifications::{NotificationLayer, Notify, NotifyIcon};
+use crate::ui::scenes::in_game::cameras::{
+    CursorState, InGameCamera, LocalPlayerSlot, LocalPlayers,
+};
+use bevy::input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel};
+use bevy::post_process::dof::{DepthOfField, DepthOfFieldMode};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk};
+use bevy_paths::PathRegistry;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+/// Key that takes a screenshot
+pub const SCREENSHOT_KEY: KeyCode = KeyCode::F12;
+
+const PITCH_LIMIT: f32 = FRAC_PI_2 - 0.01;
+/// Pixel scroll events per scroll line
+const PIXELS_PER_LINE: f32 = 100.0;
+/// Field of view limits (radians)
+const MIN_FOV: f32 = 0.17;
+const MAX_FOV: f32 = 1.75;
+/// Roll speed in radians per second
+const ROLL_SPEED: f32 = 1.0;
+/// Focus distance limits (meters)
+const MIN_FOCAL_DISTANCE: f32 = 0.5;
+const MAX_FOCAL_DISTANCE: f32 = 500.0;
+/// Aperture limits (f-stops), lower blurs more
+const MIN_APERTURE: f32 = 0.5;
+const MAX_APERTURE: f32 = 32.0;
+
+pub(super) struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenshotSettings>()
+            .add_observer(enter_photo_mode)
+            .add_observer(exit_photo_mode)
+            .add_systems(
+                Update,
+                (
+                    (handle_photo_input, apply_photo_camera)
+                        .chain()
+                        .run_if(in_photo_mode)
+                        .run_if(console_allows_input),
+                    // Not `gameplay_input_allowed`: screenshots work in photo mode and of the map
+                    take_screenshot
+                        .run_if(chat_allows_input)
+                        .run_if(console_allows_input),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            )
+            // Systems like the per-player HUD and the nameplates set their visibility every frame
+            .add_systems(
+                PostUpdate,
+                keep_ui_hidden
+                    .run_if(in_photo_mode)
+                    .after(NameplateSystems)
+                    .before(bevy::camera::visibility::VisibilitySystems::VisibilityPropagate),
+            )
+            .add_systems(OnExit(GameState::InGame), leave_photo_mode_on_exit);
+    }
+}
+
+/// Screenshot preferences, persisted as the "screenshots" section of the settings file
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct ScreenshotSettings {
+    /// Writes a JSON file with the world and positions next to every screenshot
+    pub metadata: bool,
+}
+
+impl Default for ScreenshotSettings {
+    fn default() -> Self {
+        Self { metadata: true }
+    }
+}
+
+/// Event entering photo mode
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnterPhotoMode;
+
+/// Event leaving photo mode and returning to the in-game menu
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExitPhotoMode;
+
+/// The free camera of photo mode
+#[derive(Component)]
+struct PhotoCamera {
+    position: Vec3,
+    yaw: f32,
+    pitch: f32,
+    roll: f32,
+    fov: f32,
+    sensitivity: f32,
+    /// Movement speed (m/s)
+    speed: f32,
+    /// Speed factor while Shift is held
+    boost_multiplier: f32,
+    depth_of_field: bool,
+    focal_distance: f32,
+    aperture_f_stops: f32,
+    /// Whether the virtual clock ran before photo mode paused it
+    resume_time: bool,
+}
+
+impl PhotoCamera {
+    fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, self.roll)
+    }
+}
+
+/// UI hidden by photo mode, with the visibility to restore
+#[derive(Component)]
+struct PhotoModeHidden(Visibility);
+
+/// Contents of the JSON file written next to a screenshot
+#[derive(Serialize)]
+struct ScreenshotMetadata {
+    taken_at: String,
+    /// Name of the world, None if the session didn't name it
+    world: Option<String>,
+    player_position: Option<[f32; 3]>,
+    camera_position: Option<[f32; 3]>,
+    /// Vertical field of view in degrees
+    fov: Option<f32>,
+    photo_mode: bool,
+}
+
+fn in_photo_mode(photo: Res<PhotoModeState>) -> bool {
+    photo.is_active()
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn enter_photo_mode(
+    _event: On<EnterPhotoMode>,
+    mut commands: Commands,
+    mut photo: ResMut<PhotoModeState>,
+    mut menu: ResMut<InGameMenuState>,
+    mut cursor: ResMut<CursorState>,
+    mut time: ResMut<Time<Virtual>>,
+    local_players: Res<LocalPlayers>,
+    mut cameras: Query<
+        (&mut Camera, &GlobalTransform, &Projection, &LocalPlayerSlot),
+        With<InGameCamera>,
+    >,
+    ui: Query<
+        (Entity, &Visibility),
+        (
+            Or<(
+                With<InGameHUD>,
+                With<ChatHud>,
+                With<MinimapUI>,
+                With<NotificationLayer>,
+            )>,
+            Without<PhotoModeHidden>,
+        ),
+    >,
+) {
+    if photo.is_active() {
+        return;
+    }
+    let Some((transform, fov)) =
+        cameras
+            .iter()
+            .find(|(.., slot)| slot.is_primary())
+            .map(|(_, global, projection, _)| {
+                let fov = match projection {
+                    Projection::Perspective(perspective) => perspective.fov,
+                    _ => PerspectiveProjection::default().fov,
+                };
+                (global.compute_transform(), fov)
+            })
+    else {
+        warn!(target: LOG_CLIENT, "No player camera to start photo mode from");
+        return;
+    };
+
+    for (mut camera, ..) in &mut cameras {
+        camera.is_active = false;
+    }
+
+    let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
+    let view = PhotoCamera {
+        position: transform.translation,
+        yaw,
+        pitch: pitch.clamp(-PITCH_LIMIT, PITCH_LIMIT),
+        roll,
+        fov,
+        sensitivity: 0.002,
+        speed: 5.0,
+        boost_multiplier: 4.0,
+        depth_of_field: false,
+        focal_distance: 10.0,
+        aperture_f_stops: 1.0,
+        resume_time: !time.is_paused(),
+    };
+    let mut camera = commands.spawn((
+        Camera3d::default(),
+        Projection::Perspective(PerspectiveProjection { fov, ..default() }),
+        Transform::from_translation(transform.translation).with_rotation(view.rotation()),
+        view,
+        Name::new("Photo Camera"),
+    ));
+    // With a split screen the UI camera of the split screen stays in charge
+    if !local_players.is_split() {
+        camera.insert(IsDefaultUiCamera);
+    }
+
+    for (entity, visibility) in &ui {
+        commands
+            .entity(entity)
+            .insert((PhotoModeHidden(*visibility), Visibility::Hidden));
+    }
+
+    time.pause();
+    menu.set_closed();
+    *cursor = CursorState::LOCKED;
+    photo.set_active();
+    info!(target: LOG_CLIENT, "Photo mode entered");
+}
+
+#[allow(clippy::too_many_arguments)]
+fn exit_photo_mode(
+    _event: On<ExitPhotoMode>,
+    mut commands: Commands,
+    mut photo: ResMut<PhotoModeState>,
+    mut menu: ResMut<InGameMenuState>,
+    mut cursor: ResMut<CursorState>,
+    mut time: ResMut<Time<Virtual>>,
+    photo_cameras: Query<(Entity, &PhotoCamera)>,
+    mut cameras: Query<&mut Camera, With<InGameCamera>>,
+    hidden: Query<(Entity, &PhotoModeHidden)>,
+) {
+    if !photo.is_active() {
+        return;
+    }
+
+    for (entity, view) in &photo_cameras {
+        if view.resume_time {
+            time.unpause();
+        }
+        commands.entity(entity).despawn();
+    }
+    for mut camera in &mut cameras {
+        camera.is_active = true;
+    }
+    for (entity, PhotoModeHidden(visibility)) in &hidden {
+        commands
+            .entity(entity)
+            .remove::<PhotoModeHidden>()
+            .insert(*visibility);
+    }
+
+    photo.set_inactive();
+    // Back to the menu photo mode was opened from
+    menu.set_open();
+    *cursor = CursorState::FREE;
+    info!(target: LOG_CLIENT, "Photo mode left");
+}
+
+/// Leaving the game from photo mode (e.g. on a disconnect) restores clock and state.
+fn leave_photo_mode_on_exit(
+    mut commands: Commands,
+    mut photo: ResMut<PhotoModeState>,
+    mut time: ResMut<Time<Virtual>>,
+    photo_cameras: Query<(Entity, &PhotoCamera)>,
+) {
+    for (entity, view) in &photo_cameras {
+        if view.resume_time {
+            time.unpause();
+        }
+        commands.entity(entity).despawn();
+    }
+    photo.set_inactive();
+}
+
+/// Flies the photo camera; ESC leaves photo mode.
+///
+/// Runs after the menu toggle, which would otherwise close the menu again on the ESC leaving
+/// photo mode.
+pub fn handle_photo_input(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_motion: MessageReader<MouseMotion>,
+    mut mouse_wheel: MessageReader<MouseWheel>,
+    mut cameras: Query<(Entity, &mut PhotoCamera)>,
+) {
+    let Some((entity, mut view)) = cameras.iter_mut().next() else {
+        mouse_motion.clear();
+        mouse_wheel.clear();
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::Escape) {
+        commands.trigger(ExitPhotoMode);
+        return;
+    }
+
+    // Zoomed in views turn slower, so aiming stays precise
+    let sensitivity = view.sensitivity * view.fov / PerspectiveProjection::default().fov;
+    for motion in mouse_motion.read() {
+        let delta = motion.delta * sensitivity;
+        view.yaw = (view.yaw - delta.x).rem_euclid(TAU);
+        view.pitch = (view.pitch - delta.y).clamp(-PITCH_LIMIT, PITCH_LIMIT);
+    }
+
+    for wheel in mouse_wheel.read() {
+        let lines = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / PIXELS_PER_LINE,
+        };
+        // Scrolling up zooms in
+        view.fov = (view.fov * 0.9f32.powf(lines)).clamp(MIN_FOV, MAX_FOV);
+    }
+
+    let dt = time.delta_secs();
+
+    if keyboard.just_pressed(KeyCode::KeyR) {
+        view.roll = 0.0;
+    }
+    if keyboard.pressed(KeyCode::KeyZ) {
+        view.roll += ROLL_SPEED * dt;
+    }
+    if keyboard.pressed(KeyCode::KeyX) {
+        view.roll -= ROLL_SPEED * dt;
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        view.depth_of_field = !view.depth_of_field;
+        if view.depth_of_field {
+            commands.entity(entity).insert(DepthOfField {
+                mode: DepthOfFieldMode::Gaussian,
+                focal_distance: view.focal_distance,
+                aperture_f_stops: view.aperture_f_stops,
+                ..default()
+            });
+        } else {
+            commands.entity(entity).remove::<DepthOfField>();
+        }
+    }
+    // Focus and aperture change by a factor per second
+    if keyboard.pressed(KeyCode::ArrowUp) {
+        view.focal_distance = (view.focal_distance * 2f32.powf(dt)).min(MAX_FOCAL_DISTANCE);
+    }
+    if keyboard.pressed(KeyCode::ArrowDown) {
+        view.focal_distance = (view.focal_distance / 2f32.powf(dt)).max(MIN_FOCAL_DISTANCE);
+    }
+    if keyboard.pressed(KeyCode::ArrowRight) {
+        view.aperture_f_stops = (view.aperture_f_stops * 2f32.powf(dt)).min(MAX_APERTURE);
+    }
+    if keyboard.pressed(KeyCode::ArrowLeft) {
+        view.aperture_f_stops = (view.aperture_f_stops / 2f32.powf(dt)).max(MIN_APERTURE);
+    }
+
+    // Move along the view direction without the roll, Q/E straight down/up
+    let rotation = Quat::from_rotation_y(view.yaw) * Quat::from_rotation_x(view.pitch);
+    let mut direction = Vec3::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        direction += rotation * Vec3::NEG_Z;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        direction -= rotation * Vec3::NEG_Z;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        direction += rotation * Vec3::X;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        direction -= rotation * Vec3::X;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        direction += Vec3::Y;
+    }
+    if keyboard.pressed(KeyCode::KeyQ) {
+        direction -= Vec3::Y;
+    }
+    let boost = if keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        view.boost_multiplier
+    } else {
+        1.0
+    };
+    // Real time, the virtual clock is paused
+    let velocity = direction.normalize_or_zero() * view.speed * boost;
+    view.position += velocity * dt;
+}
+
+fn apply_photo_camera(
+    mut cameras: Query<(
+        &PhotoCamera,
+        &mut Transform,
+        &mut Projection,
+        Option<&mut DepthOfField>,
+    )>,
+) {
+    for (view, mut transform, mut projection, depth_of_field) in &mut cameras {
+        transform.translation = view.position;
+        transform.rotation = view.rotation();
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = view.fov;
+        }
+        if let Some(mut depth_of_field) = depth_of_field {
+            depth_of_field.focal_distance = view.focal_distance;
+            depth_of_field.aperture_f_stops = view.aperture_f_stops;
+        }
+    }
+}
+
+/// Nameplates aren't restored on leave, their systems set the visibility again every frame.
+#[allow(clippy::type_complexity)]
+fn keep_ui_hidden(
+    mut hidden: Query<&mut Visibility, Or<(With<PhotoModeHidden>, With<Nameplate>)>>,
+) {
+    for mut visibility in &mut hidden {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+}
+
+/// Saves the window as PNG with F12, in photo mode and outside of it.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn take_screenshot(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    registry: Res<PathRegistry>,
+    settings: Res<ScreenshotSettings>,
+    photo: Res<PhotoModeState>,
+    world_name: Option<Res<WorldName>>,
+    players: Query<(&Transform, &LocalPlayerSlot), With<LocalPlayer>>,
+    photo_cameras: Query<(&Transform, &Projection), With<PhotoCamera>>,
+    cameras: Query<(&Transform, &Projection, &LocalPlayerSlot), With<InGameCamera>>,
+) {
+    if !keyboard.just_pressed(SCREENSHOT_KEY) {
+        return;
+    }
+
+    let Some(dir) = registry.get::<ScreenshotsDir>() else {
+        warn!(target: LOG_CLIENT, "No screenshots directory registered");
+        return;
+    };
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        warn!(target: LOG_CLIENT, "Failed to create the screenshots directory: {}", err);
+        return;
+    }
+
+    let now = chrono::Local::now();
+    let name = format!("screenshot_{}", now.format("%Y%m%d-%H%M%S-%3f"));
+    let path = dir.join(format!("{name}.png"));
+    let mut save = save_to_disk(path.clone());
+    let file_name = format!("{name}.png");
+    // The capture arrives a few frames later, confirm once the file is written
+    commands.spawn(Screenshot::primary_window()).observe(
+        move |captured: On<ScreenshotCaptured>, mut commands: Commands| {
+            save(captured);
+            if path.exists() {
+                info!(target: LOG_CLIENT, "Screenshot saved to {}", path.display());
+                commands.trigger(
+                    Notify::info(format!("Screenshot saved: {file_name}"))
+                        .with_icon(NotifyIcon::Check),
+                );
+            } else {
+                commands.trigger(Notify::error(format!("Failed to save {file_name}")));
+            }
+        },
+    );
+
+    if settings.metadata {
+        // The photo camera, or the camera of the primary player
+        let camera = photo_cameras.iter().next().or_else(|| {
+            cameras
+                .iter()
+                .find(|(.., slot)| slot.is_primary())
+                .map(|(transform, projection, _)| (transform, projection))
+        });
+        let metadata = ScreenshotMetadata {
+            taken_at: now.to_rfc3339(),
+            world: world_name.map(|world_name| world_name.0.clone()),
+            player_position: LocalPlayerSlot::PRIMARY
+                .find(players.iter())
+                .map(|player| player.translation.to_array()),
+            camera_position: camera.map(|(transform, _)| transform.translation.to_array()),
+            fov: camera.and_then(|(_, projection)| match projection {
+                Projection::Perspective(perspective) => Some(perspective.fov.to_degrees()),
+                _ => None,
+            }),
+            photo_mode: photo.is_active(),
+        };
+        let written = serde_json::to_string_pretty(&metadata)
+            .map_err(|err| err.to_string())
+            .and_then(|json| {
+                std::fs::write(dir.join(format!("{name}.json")), json)
+                    .map_err(|err| err.to_string())
+            });
+        if let Err(err) = written {
+            warn!(target: LOG_CLIENT, "Failed to save screenshot metadata: {}", err);
+        }
+    }
+}
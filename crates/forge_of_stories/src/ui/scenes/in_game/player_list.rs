@@ -0,0 +1,328 @@
+//! Player list / scoreboard overlay
+//!
+//! While **Tab** is held, a panel in the centre of the screen lists every replicated player
+//! with colour swatch, display name, role badge and round-trip time. Ping and role are
+//! replicated by the server on the player entity; until they arrive the local player shows its
+//! own `RenetClient` round trip, and is the host while it runs the embedded server.
+
+use super::hud::InGameHUD;
+use super::world::DisplayName;
+use crate::GameState;
+use crate::networking::LocalPlayer;
+use crate::ui::components::{ChatInputState, ConsoleState, PhotoModeState};
+use bevy::prelude::*;
+use bevy_replicon_renet::renet::RenetClient;
+use game_server::{Player, ServerHandle};
+use serde::{Deserialize, Serialize};
+
+/// Seconds between refreshes of the list while it is shown.
+const REFRESH_INTERVAL_SECS: f32 = 0.5;
+
+const HEADER_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const LOCAL_COLOR: Color = Color::srgb(0.6, 0.85, 1.0);
+
+pub(super) struct PlayerListPlugin;
+
+impl Plugin for PlayerListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_player_list)
+            .add_systems(
+                Update,
+                (toggle_player_list, rebuild_player_list)
+                    .chain()
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+/// Round-trip time of the owning client in milliseconds, measured and replicated by the server.
+/// The local player's own ping is read from the `RenetClient` when it isn't replicated.
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClientPing(pub u32);
+
+/// Role of a player in the session, replicated by the server.
+#[derive(Component, Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlayerRole {
+    #[default]
+    Player,
+    Admin,
+    /// The player hosting the (embedded) server
+    Host,
+}
+
+impl PlayerRole {
+    /// Whether the role may run admin commands
+    pub fn is_admin(self) -> bool {
+        matches!(self, PlayerRole::Admin | PlayerRole::Host)
+    }
+
+    fn badge(self) -> Option<(&'static str, Color)> {
+        match self {
+            PlayerRole::Player => None,
+            PlayerRole::Admin => Some(("ADMIN", Color::srgb(0.9, 0.45, 0.3))),
+            PlayerRole::Host => Some(("HOST", Color::srgb(0.95, 0.8, 0.35))),
+        }
+    }
+}
+
+/// Root of the player list overlay
+#[derive(Component)]
+struct PlayerListPanel;
+
+/// Container for the player rows
+#[derive(Component)]
+struct PlayerListRows;
+
+fn spawn_player_list(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Percent(15.0),
+                justify_content: JustifyContent::Center,
+                display: Display::None,
+                ..default()
+            },
+            Pickable::IGNORE,
+            GlobalZIndex(20),
+            PlayerListPanel,
+            InGameHUD,
+            Name::new("Player List"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        padding: UiRect::all(Val::Px(12.0)),
+                        row_gap: Val::Px(6.0),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    BorderRadius::all(Val::Px(8.0)),
+                    BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.85)),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Players"),
+                        TextFont {
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                    ));
+                    panel.spawn((
+                        Node {
+                            row_gap: Val::Px(4.0),
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        PlayerListRows,
+                    ));
+                });
+        });
+}
+
+/// Shows the overlay while Tab is held (not while typing).
+fn toggle_player_list(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    chat: Res<ChatInputState>,
+    console: Res<ConsoleState>,
+    photo: Res<PhotoModeState>,
+    mut panel: Query<&mut Node, With<PlayerListPanel>>,
+) {
+    let show = keyboard.pressed(KeyCode::Tab)
+        && !chat.is_open()
+        && !console.is_open()
+        && !photo.is_active();
+
+    for mut node in &mut panel {
+        let display = if show { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// Rebuilds the rows when the overlay is opened and periodically while it is shown.
+fn rebuild_player_list(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut next_refresh: Local<f32>,
+    panel: Query<Ref<Node>, With<PlayerListPanel>>,
+    rows: Query<Entity, With<PlayerListRows>>,
+    players: Query<(
+        &Player,
+        Option<&DisplayName>,
+        Option<&ClientPing>,
+        Option<&PlayerRole>,
+        Has<LocalPlayer>,
+    )>,
+    client: Option<Res<RenetClient>>,
+    server: Option<Res<ServerHandle>>,
+) {
+    let Ok(node) = panel.single() else {
+        return;
+    };
+    if node.display == Display::None {
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    if !node.is_changed() && now < *next_refresh {
+        return;
+    }
+    *next_refresh = now + REFRESH_INTERVAL_SECS;
+
+    let Ok(rows) = rows.single() else {
+        return;
+    };
+
+    let mut entries: Vec<_> = players
+        .iter()
+        .map(|(player, name, ping, role, is_local)| {
+            // Our own round trip is known locally, and with an embedded server we are its host
+            let ping = ping.copied().or_else(|| {
+                client
+                    .as_ref()
+                    .filter(|_| is_local)
+                    .map(|client| ClientPing((client.rtt() * 1000.0).round() as u32))
+            });
+            let role = role.copied().unwrap_or(if is_local && server.is_some() {
+                PlayerRole::Host
+            } else {
+                PlayerRole::Player
+            });
+            (
+                player.color,
+                DisplayName::of(name).to_string(),
+                ping,
+                role,
+                is_local,
+            )
+        })
+        .collect();
+    // Host first, then admins, then alphabetically
+    entries.sort_by(|a, b| {
+        let rank = |role: PlayerRole| match role {
+            PlayerRole::Host => 0,
+            PlayerRole::Admin => 1,
+            PlayerRole::Player => 2,
+        };
+        rank(a.3)
+            .cmp(&rank(b.3))
+            .then_with(|| a.1.to_lowercase().cmp(&b.1.to_lowercase()))
+    });
+
+    commands.entity(rows).despawn_children();
+    commands.entity(rows).with_children(|rows| {
+        spawn_row(
+            rows,
+            None,
+            "Name".to_string(),
+            None,
+            "Ping".to_string(),
+            HEADER_COLOR,
+            HEADER_COLOR,
+        );
+
+        for (color, name, ping, role, is_local) in entries {
+            let (ping_text, ping_color) = match ping {
+                Some(ClientPing(ms)) => (format!("{ms} ms"), ping_color(ms)),
+                None => ("-".to_string(), HEADER_COLOR),
+            };
+            let name_color = if is_local { LOCAL_COLOR } else { TEXT_COLOR };
+            spawn_row(
+                rows,
+                Some(color),
+                name,
+                role.badge(),
+                ping_text,
+                name_color,
+                ping_color,
+            );
+        }
+    });
+}
+
+fn ping_color(ms: u32) -> Color {
+    match ms {
+        0..=80 => Color::srgb(0.4, 0.85, 0.4),
+        81..=150 => Color::srgb(0.95, 0.8, 0.35),
+        _ => Color::srgb(0.95, 0.4, 0.35),
+    }
+}
+
+fn spawn_row(
+    rows: &mut ChildSpawnerCommands,
+    swatch: Option<Color>,
+    name: String,
+    badge: Option<(&'static str, Color)>,
+    ping: String,
+    name_color: Color,
+    ping_color: Color,
+) {
+    rows.spawn(Node {
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        ..default()
+    })
+    .with_children(|row| {
+        row.spawn((
+            Node {
+                width: Val::Px(14.0),
+                height: Val::Px(14.0),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(3.0)),
+            BackgroundColor(swatch.unwrap_or(Color::NONE)),
+        ));
+        row.spawn((
+            Text::new(name),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(name_color),
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+        ));
+        if let Some((label, color)) = badge {
+            row.spawn((
+                Node {
+                    padding: UiRect::axes(Val::Px(6.0), Val::Px(1.0)),
+                    ..default()
+                },
+                BorderRadius::all(Val::Px(4.0)),
+                BackgroundColor(color),
+            ))
+            .with_children(|badge| {
+                badge.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Color::BLACK),
+                ));
+            });
+        }
+        row.spawn((
+            Text::new(ping),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(ping_color),
+            Node {
+                width: Val::Px(70.0),
+                justify_content: JustifyContent::FlexEnd,
+                ..default()
+            },
+        ));
+    });
+}
@@ -1,14 +1,17 @@
 mod assets;
 mod lighting;
+mod nameplates;
 mod visual_spawners;
 
 use crate::networking::LocalPlayer;
+use crate::ui::debug_overlay::DebugOverlayAppExt;
 use crate::ui::normal_vector::LocalCoordinateSystem;
 use crate::ui::scenes::in_game::cameras::InGameCamera;
 use crate::{GameState, utils::cleanup};
 pub use assets::RenderAssets;
 use bevy::prelude::*;
 pub use lighting::LightingPlugin;
+pub use nameplates::{DisplayName, Nameplate, NameplateSystems, NameplatesPlugin};
 pub use visual_spawners::VisualSpawnersPlugin;
 
 #[derive(Component)]
@@ -19,7 +22,8 @@ pub struct InGameWorldPlugin;
 
 impl Plugin for InGameWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((LightingPlugin, VisualSpawnersPlugin))
+        app.add_plugins((LightingPlugin, VisualSpawnersPlugin, NameplatesPlugin))
+            .register_debug_marker::<InGameWorld>("InGameWorld")
             .add_systems(OnEnter(GameState::InGame), setup_render_assets)
             .add_systems(OnExit(GameState::InGame), cleanup::<InGameWorld>);
     }
@@ -0,0 +1,270 @@
+//! World-space nameplates above replicated players
+//!
+//! Nameplates are attached when a player's visuals spawn. They are UI text nodes projected from
+//! the player's head position into the viewport of an `InGameCamera`; with a split screen every
+//! player camera has its own set. They fade out with distance, are hidden when the head is
+//! occluded by other geometry, and a camera's own player's plate is hidden in first person.
+
+use super::InGameWorld;
+use super::visual_spawners::HasVisuals;
+use crate::GameState;
+use crate::networking::LocalPlayer;
+use crate::ui::scenes::in_game::cameras::{
+    ActiveCameraMode, CameraMode, InGameCamera, LocalPlayerSlot,
+};
+use bevy::picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy::transform::TransformSystems;
+use game_server::Player;
+use serde::{Deserialize, Serialize};
+
+/// Height above the player origin where the nameplate is anchored.
+const NAMEPLATE_HEIGHT: f32 = 1.4;
+/// Distance at which nameplates start fading out.
+const FADE_START: f32 = 12.0;
+/// Distance at which nameplates are fully transparent.
+const FADE_END: f32 = 30.0;
+
+/// Plugin for player nameplates
+pub struct NameplatesPlugin;
+
+impl Plugin for NameplatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                despawn_orphaned_nameplates,
+                spawn_camera_nameplates,
+                update_nameplate_text,
+                update_nameplate_positions,
+            )
+                .chain()
+                .in_set(NameplateSystems)
+                .run_if(in_state(GameState::InGame))
+                .after(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// Systems placing the nameplates and setting their visibility
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct NameplateSystems;
+
+/// Replicated display name of a player, assigned by the server.
+#[derive(Component, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayName(pub String);
+
+impl DisplayName {
+    /// Shown for players the server hasn't named (yet)
+    pub const UNNAMED: &'static str = "Unnamed";
+
+    /// The name of a player, or [`Self::UNNAMED`]
+    pub fn of(name: Option<&DisplayName>) -> &str {
+        name.map(|name| name.0.as_str())
+            .filter(|name| !name.is_empty())
+            .unwrap_or(Self::UNNAMED)
+    }
+}
+
+/// UI nameplate following a player entity in the viewport of a player camera
+#[derive(Component)]
+pub struct Nameplate {
+    pub target: Entity,
+    pub camera: Entity,
+}
+
+/// Attaches nameplates for `target` in the viewports of `cameras`.
+pub(super) fn spawn_nameplates(
+    commands: &mut Commands,
+    target: Entity,
+    cameras: impl IntoIterator<Item = Entity>,
+) {
+    for camera in cameras {
+        spawn_nameplate(commands, target, camera);
+    }
+}
+
+/// Spawns the nameplate UI node for `target` in the viewport of `camera`.
+fn spawn_nameplate(commands: &mut Commands, target: Entity, camera: Entity) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        TextShadow::default(),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        // Anchor at bottom centre so the text sits above the head
+        UiTransform::from_translation(Val2::percent(-50.0, -100.0)),
+        UiTargetCamera(camera),
+        Visibility::Hidden,
+        Nameplate { target, camera },
+        InGameWorld,
+        Name::new("Player Nameplate"),
+    ));
+}
+
+fn despawn_orphaned_nameplates(
+    mut commands: Commands,
+    nameplates: Query<(Entity, &Nameplate)>,
+    players: Query<(), With<Player>>,
+    cameras: Query<(), With<InGameCamera>>,
+) {
+    for (entity, nameplate) in &nameplates {
+        if !players.contains(nameplate.target) || !cameras.contains(nameplate.camera) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Gives players whose visuals already spawned a nameplate in the viewport of a camera added
+/// later (entering the game, a local player joining the split screen).
+fn spawn_camera_nameplates(
+    mut commands: Commands,
+    cameras: Query<Entity, Added<InGameCamera>>,
+    players: Query<Entity, (With<Player>, With<HasVisuals>)>,
+    nameplates: Query<&Nameplate>,
+) {
+    if cameras.is_empty() {
+        return;
+    }
+
+    let existing: HashSet<_> = nameplates
+        .iter()
+        .map(|nameplate| (nameplate.target, nameplate.camera))
+        .collect();
+    for camera in &cameras {
+        for target in &players {
+            if !existing.contains(&(target, camera)) {
+                spawn_nameplate(&mut commands, target, camera);
+            }
+        }
+    }
+}
+
+/// Shows the player's name, or a placeholder until the server sent one.
+fn update_nameplate_text(
+    mut nameplates: Query<(&Nameplate, &mut Text)>,
+    players: Query<Option<&DisplayName>, With<Player>>,
+) {
+    for (nameplate, mut text) in &mut nameplates {
+        let Ok(name) = players.get(nameplate.target) else {
+            continue;
+        };
+        let name = DisplayName::of(name);
+        if text.0 != name {
+            text.0 = name.to_string();
+        }
+    }
+}
+
+/// Places every nameplate in the viewport of its camera.
+#[allow(clippy::type_complexity)]
+fn update_nameplate_positions(
+    mut ray_cast: MeshRayCast,
+    cameras: Query<
+        (
+            &Camera,
+            &GlobalTransform,
+            &Projection,
+            &ActiveCameraMode,
+            &LocalPlayerSlot,
+        ),
+        With<InGameCamera>,
+    >,
+    players: Query<&GlobalTransform, With<Player>>,
+    local_players: Query<(Entity, &LocalPlayerSlot), With<LocalPlayer>>,
+    mut nameplates: Query<(
+        &Nameplate,
+        &Text,
+        &mut Node,
+        &mut TextColor,
+        &mut Visibility,
+    )>,
+) {
+    for (nameplate, text, mut node, mut color, mut visibility) in &mut nameplates {
+        let (Ok((camera, camera_transform, projection, active_mode, slot)), Ok(player_transform)) =
+            (cameras.get(nameplate.camera), players.get(nameplate.target))
+        else {
+            continue;
+        };
+
+        // In first person the camera sits inside its own player's capsule
+        let own_player = slot.find(local_players.iter());
+        let first_person = active_mode.mode == CameraMode::FirstPerson;
+        let ignored = own_player.filter(|_| first_person);
+
+        // Own plate is never useful from inside the head
+        if text.0.is_empty() || (first_person && own_player == Some(nameplate.target)) {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let camera_position = camera_transform.translation();
+        // An orthographic camera sits far behind its focus, fade by the distance to the focus
+        // instead
+        let fade_origin = match projection {
+            Projection::Orthographic(orthographic) => {
+                camera_position
+                    + camera_transform.forward() * (orthographic.near + orthographic.far) / 2.0
+            }
+            _ => camera_position,
+        };
+
+        let head = player_transform.translation() + Vec3::Y * NAMEPLATE_HEIGHT;
+        let distance = fade_origin.distance(head);
+
+        let Ok(viewport_position) = camera.world_to_viewport(camera_transform, head) else {
+            // Behind the camera or outside the viewport
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        if distance >= FADE_END
+            || is_occluded(
+                &mut ray_cast,
+                camera_position,
+                head,
+                nameplate.target,
+                ignored,
+            )
+        {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let alpha = 1.0 - ((distance - FADE_START) / (FADE_END - FADE_START)).clamp(0.0, 1.0);
+        color.0 = Color::WHITE.with_alpha(alpha);
+        node.left = Val::Px(viewport_position.x);
+        node.top = Val::Px(viewport_position.y);
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Returns true if any mesh other than the target (or the ignored entity) sits between the
+/// camera and the nameplate anchor.
+fn is_occluded(
+    ray_cast: &mut MeshRayCast,
+    from: Vec3,
+    to: Vec3,
+    target: Entity,
+    ignored: Option<Entity>,
+) -> bool {
+    let Ok(direction) = Dir3::new(to - from) else {
+        return false;
+    };
+    let distance = from.distance(to);
+
+    let filter = |entity: Entity| entity != target && Some(entity) != ignored;
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+
+    ray_cast
+        .cast_ray(Ray3d::new(from, direction), &settings)
+        .first()
+        .is_some_and(|(_, hit)| hit.distance < distance)
+}
@@ -1,6 +1,9 @@
 use super::InGameWorld;
 use super::RenderAssets;
+use super::nameplates::spawn_nameplates;
 use crate::GameState;
+use crate::ui::debug_overlay::DebugOverlayAppExt;
+use crate::ui::scenes::in_game::cameras::{CameraBlocker, IgnoreCameraCollision, InGameCamera};
 use bevy::math::primitives::Cuboid;
 use bevy::pbr::MeshMaterial3d;
 use bevy::prelude::*;
@@ -11,16 +14,19 @@ pub struct VisualSpawnersPlugin;
 
 impl Plugin for VisualSpawnersPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (spawn_ground_plane_visuals, spawn_player_visuals).run_if(in_state(GameState::InGame)),
-        );
+        app.register_debug_marker::<GroundPlane>("GroundPlane")
+            .register_debug_marker::<Player>("Player")
+            .add_systems(
+                Update,
+                (spawn_ground_plane_visuals, spawn_player_visuals)
+                    .run_if(in_state(GameState::InGame)),
+            );
     }
 }
 
 /// Marker to track which entities already have visuals spawned
 #[derive(Component)]
-struct HasVisuals;
+pub(super) struct HasVisuals;
 
 fn spawn_ground_plane_visuals(
     mut commands: Commands,
@@ -51,6 +57,7 @@ fn spawn_ground_plane_visuals(
             InheritedVisibility::default(),
             HasVisuals,
             InGameWorld,
+            CameraBlocker,
         ));
     }
 }
@@ -60,6 +67,7 @@ fn spawn_player_visuals(
     assets: Res<RenderAssets>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     players: Query<(Entity, &Player, &Transform), Without<HasVisuals>>,
+    cameras: Query<Entity, With<InGameCamera>>,
 ) {
     for (entity, player, transform) in &players {
         // info!(
@@ -90,7 +98,11 @@ fn spawn_player_visuals(
             InheritedVisibility::default(),
             HasVisuals,
             InGameWorld,
+            // Players walking past should not push the orbit camera around
+            IgnoreCameraCollision,
         ));
+
+        spawn_nameplates(&mut commands, entity, &cameras);
     }
 }
 
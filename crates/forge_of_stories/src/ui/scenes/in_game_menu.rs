@@ -2,6 +2,8 @@ use crate::GameState;
 use crate::ui::components::{HOVERED_BUTTON, InGameMenuState, NORMAL_BUTTON, PRESSED_BUTTON};
 
 use crate::app::LOG_CLIENT;
+use crate::ui::notifications::{Notify, NotifyIcon};
+use crate::ui::scenes::in_game::photo_mode::EnterPhotoMode;
 use bevy::color::palettes::basic::RED;
 use bevy::prelude::*;
 use bevy_replicon_renet::{netcode::NetcodeClientTransport, renet::RenetClient};
@@ -32,6 +34,7 @@ struct InGameMenuUI;
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
 enum InGameMenuAction {
     Resume,
+    PhotoMode,
     LeaveGame,
 }
 
@@ -122,6 +125,34 @@ fn spawn_in_game_menu_ui(
                             ));
                         });
 
+                    // Photo mode button
+                    panel
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(260.0),
+                                height: Val::Px(56.0),
+                                border: UiRect::all(Val::Px(4.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BorderColor::all(Color::WHITE),
+                            BorderRadius::all(Val::Px(10.0)),
+                            BackgroundColor(NORMAL_BUTTON),
+                            InGameMenuAction::PhotoMode,
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new("Photo Mode"),
+                                TextFont {
+                                    font_size: 22.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                            ));
+                        });
+
                     // Leave game button
                     panel
                         .spawn((
@@ -154,6 +185,7 @@ fn spawn_in_game_menu_ui(
 }
 
 fn handle_in_game_menu_buttons(
+    mut commands: Commands,
     mut interaction_query: Query<
         (
             &Interaction,
@@ -174,6 +206,8 @@ fn handle_in_game_menu_buttons(
 
                 match action {
                     InGameMenuAction::Resume => menu.set_closed(),
+                    // Closes the menu itself and brings it back on leaving
+                    InGameMenuAction::PhotoMode => commands.trigger(EnterPhotoMode),
                     InGameMenuAction::LeaveGame => {
                         info!(target: LOG_CLIENT, "Leaving game...");
                         menu.set_closed();
@@ -207,6 +241,7 @@ fn cleanup_on_leave_game(
     commands.remove_resource::<NetcodeClientTransport>();
 
     info!(target: LOG_CLIENT, "Client disconnected");
+    commands.trigger(Notify::info("Disconnected from server").with_icon(NotifyIcon::Server));
 
     // 2. Stop the embedded server if running
     if let Some(ref mut server) = server {
@@ -3,7 +3,7 @@
 //! This module contains all components for the main menu scene:
 //! - UI: 2D overlay with title, buttons, and menus
 //! - World: 3D background scene with environment and effects
-//! - Camera: Camera positioning (delegated to global camera system)
+//! - Camera: Backdrop orbit along a camera path
 //! - Input: Server connection handling and state transitions
 //!
 //! The scene-first architecture keeps all related code together,
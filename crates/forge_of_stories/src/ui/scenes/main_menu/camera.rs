@@ -1,15 +1,23 @@
 // scenes/main_menu/camera.rs
 
 use crate::GameState;
+use crate::ui::scenes::camera_path::{CameraKeyframe, CameraPath, CameraPathPlayer, PathPlayback};
 use crate::utils::cleanup;
 use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Keyframes of the backdrop orbit
+const ORBIT_KEYFRAMES: usize = 8;
+const ORBIT_RADIUS: f32 = 8.0;
+const ORBIT_HEIGHT: f32 = 1.5;
+/// Seconds per full orbit
+const ORBIT_DURATION: f32 = 60.0;
 
 pub(super) struct MainMenuCameraPlugin;
 
 impl Plugin for MainMenuCameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::MainMenu), spawn_camera)
-            .add_systems(Update, animate_orbit.run_if(in_state(GameState::MainMenu)))
             .add_systems(OnExit(GameState::MainMenu), cleanup::<MainMenuCamera>);
     }
 }
@@ -17,22 +25,32 @@ impl Plugin for MainMenuCameraPlugin {
 #[derive(Component)]
 struct MainMenuCamera;
 
-fn spawn_camera(mut commands: Commands) {
+fn spawn_camera(mut commands: Commands, mut paths: ResMut<Assets<CameraPath>>) {
+    let path = paths.add(orbit_path());
+
     commands.spawn((
         Camera3d::default(),
-        Transform::from_xyz(0.0, 1.5, 8.0).looking_at(Vec3::ZERO, Vec3::Y),
+        Transform::from_xyz(ORBIT_RADIUS, ORBIT_HEIGHT, 0.0).looking_at(Vec3::ZERO, Vec3::Y),
+        CameraPathPlayer::new(path, PathPlayback::Loop),
         MainMenuCamera,
         Name::new("Main Menu Camera"),
     ));
 }
 
-fn animate_orbit(time: Res<Time>, mut cameras: Query<&mut Transform, With<MainMenuCamera>>) {
-    for mut transform in &mut cameras {
-        let radius = 8.0;
-        let speed = 0.1;
-        let angle = time.elapsed_secs() * speed;
+/// Slow closed orbit around the scene origin
+fn orbit_path() -> CameraPath {
+    let step = ORBIT_DURATION / ORBIT_KEYFRAMES as f32;
+    let keyframes = (0..ORBIT_KEYFRAMES)
+        .map(|index| {
+            let angle = index as f32 / ORBIT_KEYFRAMES as f32 * TAU;
+            let position = Vec3::new(
+                angle.cos() * ORBIT_RADIUS,
+                ORBIT_HEIGHT,
+                angle.sin() * ORBIT_RADIUS,
+            );
+            CameraKeyframe::new(index as f32 * step, position, Vec3::ZERO)
+        })
+        .collect();
 
-        transform.translation = Vec3::new(angle.cos() * radius, 1.5, angle.sin() * radius);
-        transform.look_at(Vec3::ZERO, Vec3::Y);
-    }
+    CameraPath::new(keyframes).closed(step)
 }
@@ -2,8 +2,9 @@
 //!
 //! Handles server connection logic and state transitions for the main menu.
 
-use crate::app::{LOG_CLIENT_HOST, LOG_MAIN};
+use crate::app::{LOG_CLIENT_HOST, LOG_MAIN, WorldName};
 use crate::networking::LocalClientId;
+use crate::ui::notifications::{Notify, NotifyIcon};
 use crate::{GameState, utils::cleanup};
 use bevy::prelude::*;
 use bevy_replicon_renet::{netcode::NetcodeClientTransport, renet::RenetClient};
@@ -37,6 +38,7 @@ fn log_state_entry(state: Res<State<GameState>>) {
 /// This system runs in the ConnectingToServer state and monitors the
 /// ServerHandle resource. Once the server is ready, it transitions to InGame.
 fn wait_for_server_ready(
+    mut commands: Commands,
     server: Option<Res<game_server::ServerHandle>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
@@ -46,6 +48,7 @@ fn wait_for_server_ready(
                 target: LOG_CLIENT_HOST,
                 "Server is ready! Transitioning to InGame state"
             );
+            commands.trigger(Notify::success("Server ready").with_icon(NotifyIcon::Server));
             next_state.set(GameState::InGame);
         }
     }
@@ -67,6 +70,7 @@ fn cleanup_game_resources(
     commands.remove_resource::<RenetClient>();
     commands.remove_resource::<NetcodeClientTransport>();
     commands.remove_resource::<LocalClientId>();
+    commands.remove_resource::<WorldName>();
 
     info!(target: LOG_CLIENT_HOST, "Server stopped, resources cleaned up");
 }
@@ -3,7 +3,7 @@
 //! Contains all 2D UI elements for the main menu (title, buttons, panels).
 
 use crate::GameState;
-use crate::app::LOG_CLIENT_HOST;
+use crate::app::{LOG_CLIENT_HOST, WorldName};
 use crate::ui::components::{HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON};
 use crate::utils::cleanup;
 use bevy::color::palettes::basic::RED;
@@ -139,6 +139,7 @@ fn handle_button_interactions(
                         let server =
                             game_server::ServerHandle::start_embedded(game_server::Port(5000));
                         commands.insert_resource(server);
+                        commands.insert_resource(WorldName(WorldName::SINGLEPLAYER.to_string()));
 
                         info!(target: LOG_CLIENT_HOST, "Transitioning to ConnectingToServer state...");
                         next_state.set(GameState::ConnectingToServer);
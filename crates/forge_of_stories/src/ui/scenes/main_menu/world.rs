@@ -2,6 +2,7 @@
 //!
 //! Contains all 3D entities for the main menu (background scene, environment, effects).
 
+use crate::ui::debug_overlay::DebugOverlayAppExt;
 use crate::{GameState, utils::cleanup};
 use bevy::color::palettes::css::*;
 use bevy::prelude::*;
@@ -11,7 +12,8 @@ pub(super) struct MainMenuWorldPlugin;
 
 impl Plugin for MainMenuWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::MainMenu), spawn_world)
+        app.register_debug_marker::<MainMenuWorld>("MainMenuWorld")
+            .add_systems(OnEnter(GameState::MainMenu), spawn_world)
             .add_systems(
                 Update,
                 (animate_background, rotate_ambient_objects).run_if(in_state(GameState::MainMenu)),
@@ -3,7 +3,7 @@
 //! This module contains all components for the splashscreen scene:
 //! - UI: 2D overlay with text and instructions
 //! - World: 3D logo mesh and lighting
-//! - Camera: Camera positioning (delegated to global camera system)
+//! - Camera: Push-in shot along a camera path
 //! - Input: Skip functionality and auto-transition logic
 //!
 //! The scene-first architecture keeps all related code together,
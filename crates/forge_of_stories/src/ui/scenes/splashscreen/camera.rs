@@ -1,9 +1,13 @@
 // scenes/splashscreen/camera.rs
 
 use crate::GameState;
+use crate::ui::scenes::camera_path::{CameraKeyframe, CameraPath, CameraPathPlayer, PathPlayback};
 use crate::utils::cleanup;
 use bevy::prelude::*;
 
+/// Length of the push-in towards the logo (the splashscreen lasts 3 s)
+const SHOT_DURATION: f32 = 3.0;
+
 pub(super) struct SplashscreenCameraPlugin;
 
 impl Plugin for SplashscreenCameraPlugin {
@@ -19,11 +23,28 @@ impl Plugin for SplashscreenCameraPlugin {
 #[derive(Component)]
 struct SplashscreenCamera;
 
-fn spawn_camera(mut commands: Commands) {
+fn spawn_camera(mut commands: Commands, mut paths: ResMut<Assets<CameraPath>>) {
+    let path = paths.add(push_in_path());
+
     commands.spawn((
         Camera3d::default(),
         Transform::from_xyz(0.0, 2.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+        CameraPathPlayer::new(path, PathPlayback::Once),
         SplashscreenCamera,
         Name::new("Splashscreen Camera"),
     ));
 }
+
+/// Slow push-in that settles on the logo
+fn push_in_path() -> CameraPath {
+    CameraPath::new(vec![
+        CameraKeyframe::new(0.0, Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO)
+            .with_ease(EaseFunction::CubicOut),
+        CameraKeyframe::new(
+            SHOT_DURATION,
+            Vec3::new(0.0, 1.4, 3.8),
+            Vec3::new(0.0, 0.5, 0.0),
+        )
+        .with_fov(40.0),
+    ])
+}
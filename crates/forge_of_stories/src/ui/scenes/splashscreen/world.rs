@@ -3,6 +3,7 @@
 //! Contains all 3D entities for the splashscreen (logo mesh, lighting, animations).
 
 use crate::GameState;
+use crate::ui::debug_overlay::DebugOverlayAppExt;
 use crate::utils::cleanup;
 use bevy::color::palettes::css::*;
 use bevy::prelude::*;
@@ -12,7 +13,8 @@ pub(super) struct SplashscreenWorldPlugin;
 
 impl Plugin for SplashscreenWorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Splashscreen), spawn_world)
+        app.register_debug_marker::<SplashscreenWorld>("SplashscreenWorld")
+            .add_systems(OnEnter(GameState::Splashscreen), spawn_world)
             .add_systems(
                 Update,
                 animate_logo.run_if(in_state(GameState::Splashscreen)),